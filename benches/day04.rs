@@ -0,0 +1,28 @@
+//! Compares mining an MD5 hash with five leading zeroes, once hashing one candidate at a time
+//! and once hashing several candidates side by side.
+//!
+//! Uses `data/inputs/04.txt` if it exists, and otherwise the seed of the first example.
+
+use std::fs;
+use std::hint::black_box;
+
+use advent_of_code::days::day04::{mine_lanes, mine_scalar};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+fn bench(c: &mut Criterion) {
+    let input = fs::read_to_string("data/inputs/04.txt").unwrap_or_else(|_| "abcdef".into());
+    let seed = input.trim();
+
+    let mut group = c.benchmark_group("day04");
+    group.sample_size(10);
+    group.bench_function("scalar", |bencher| {
+        bencher.iter(|| mine_scalar(black_box(seed), 5))
+    });
+    group.bench_function("lanes", |bencher| {
+        bencher.iter(|| mine_lanes(black_box(seed), 5))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
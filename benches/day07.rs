@@ -0,0 +1,79 @@
+//! Compares evaluating the day 7 circuit with a different signal on wire `b` each time, once
+//! by resolving the wires by name and once with the circuit compiled to slots.
+//!
+//! Uses `data/inputs/07.txt` if it exists, and otherwise a generated circuit of similar size.
+
+use std::fs;
+use std::hint::black_box;
+
+use advent_of_code::days::day07::Circuit;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Generates a random circuit of `gates` gates, driving wire `a` and reading wire `b`.
+fn generate(gates: usize) -> String {
+    // A small linear congruential generator keeps the circuit deterministic.
+    let mut state = 7_u64;
+    let mut next = |bound: usize| {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (state >> 33) as usize % bound
+    };
+    let name = |i: usize| {
+        let mut name = String::from("w");
+        let mut i = i + 1;
+        while i > 0 {
+            i -= 1;
+            name.push((b'a' + (i % 26) as u8) as char);
+            i /= 26;
+        }
+        name
+    };
+
+    let mut wires = vec![String::from("b")];
+    let mut lines = vec![String::from("14146 -> b")];
+    for i in 0..gates {
+        let lhs = &wires[next(wires.len())];
+        let rhs = &wires[next(wires.len())];
+        let gate = match next(5) {
+            0 => format!("NOT {lhs}"),
+            1 => format!("{lhs} AND {rhs}"),
+            2 => format!("{lhs} OR {rhs}"),
+            3 => format!("{lhs} LSHIFT {}", 1 + next(15)),
+            _ => format!("{lhs} RSHIFT {}", 1 + next(15)),
+        };
+        lines.push(format!("{gate} -> {}", name(i)));
+        wires.push(name(i));
+    }
+    lines.push(format!("{} -> a", wires.last().unwrap()));
+    lines.join("\n")
+}
+
+fn bench(c: &mut Criterion) {
+    let input = fs::read_to_string("data/inputs/07.txt").unwrap_or_else(|_| generate(340));
+    let circuit = Circuit::parse(&input).unwrap();
+    let compiled = circuit.compile(&["b"]).unwrap();
+    let a = compiled.slot("a").unwrap();
+
+    let mut group = c.benchmark_group("day07");
+    let mut b = 0_u16;
+    group.bench_function("evaluate_with", |bencher| {
+        bencher.iter(|| {
+            b = b.wrapping_add(1);
+            let signals = circuit.evaluate_with(&[("b", black_box(b))]).unwrap();
+            signals.signal("a")
+        })
+    });
+    group.bench_function("compiled", |bencher| {
+        let mut slots = vec![];
+        bencher.iter(|| {
+            b = b.wrapping_add(1);
+            compiled.evaluate_into(&[black_box(b)], &mut slots);
+            slots[a]
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
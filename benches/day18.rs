@@ -0,0 +1,44 @@
+//! Compares 100 steps of the day 18 lights, once stepping the lights one at a time and once
+//! with the bit-packed life engine.
+//!
+//! Uses `data/inputs/18.txt` if it exists, and otherwise a generated yard of the same size.
+
+use std::fs;
+use std::hint::black_box;
+
+use advent_of_code::days::day18::Map;
+use advent_of_code::life::Life;
+use criterion::{criterion_group, criterion_main, Criterion};
+
+#[path = "../src/testing.rs"]
+mod testing;
+
+fn bench(c: &mut Criterion) {
+    let input =
+        fs::read_to_string("data/inputs/18.txt").unwrap_or_else(|_| testing::lights(100, 100, 18));
+    let input = input.trim();
+
+    let mut group = c.benchmark_group("day18");
+    group.bench_function("map", |bencher| {
+        bencher.iter(|| {
+            let mut map = Map::from(black_box(input));
+            for _ in 0..100 {
+                map.tick();
+            }
+            map.lights()
+        })
+    });
+    group.bench_function("life", |bencher| {
+        bencher.iter(|| {
+            let mut life: Life = black_box(input).parse().unwrap();
+            for _ in 0..100 {
+                life.step();
+            }
+            life.population()
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench);
+criterion_main!(benches);
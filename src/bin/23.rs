@@ -0,0 +1,3 @@
+use advent_of_code::days::day23::{part_one, part_two};
+
+advent_of_code::solution!(23);
@@ -0,0 +1,3 @@
+use advent_of_code::days::day24::{part_one, part_two};
+
+advent_of_code::solution!(24);
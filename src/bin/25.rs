@@ -0,0 +1,3 @@
+use advent_of_code::days::day25::part_one;
+
+advent_of_code::solution!(25, 1);
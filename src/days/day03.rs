@@ -0,0 +1,55 @@
+//! Day 3: Perfectly Spherical Houses in a Vacuum
+
+use std::collections::HashSet;
+
+use crate::geometry::{Direction4, Point2};
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(3);
+
+/// Returns the houses visited when `santas` deliverers take turns following the directions,
+/// all starting at the same house.
+pub fn houses(directions: &str, santas: usize) -> HashSet<Point2> {
+    let mut positions = vec![Point2::ORIGIN; santas];
+    let mut visited = HashSet::from([Point2::ORIGIN]);
+    let directions = directions
+        .trim()
+        .chars()
+        .map(|c| Direction4::try_from(c).unwrap());
+    for (i, dir) in directions.enumerate() {
+        let santa = &mut positions[i % santas];
+        *santa += dir;
+        visited.insert(*santa);
+    }
+    visited
+}
+
+/// Returns the number of houses Santa visits.
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(houses(input, 1).len())
+}
+
+/// Returns the number of houses Santa and Robo-Santa visit, taking turns.
+pub fn part_two(input: &str) -> Option<usize> {
+    Some(houses(input, 2).len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(">"), Some(2));
+        assert_eq!(part_one("^>v<"), Some(4));
+        assert_eq!(part_one("^v^v^v^v^v"), Some(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two("^v"), Some(3));
+        assert_eq!(part_two("^>v<"), Some(3));
+        assert_eq!(part_two("^v^v^v^v^v"), Some(11));
+    }
+}
@@ -0,0 +1,285 @@
+//! Day 4: The Ideal Stocking Stuffer
+
+use rayon::prelude::*;
+
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(4);
+
+/// The numbers checked by one rayon job, so that `find_first` can stop early.
+const BLOCK: usize = 1 << 16;
+
+/// The number of candidates hashed side by side by [`mine_lanes`].
+pub const LANES: usize = 8;
+
+/// The longest seed for which every candidate fits into a single MD5 block, leaving room for
+/// the 20 digits of any `usize`, the `0x80` terminator and the 8 byte message length.
+pub const MAX_LANE_SEED: usize = 64 - 20 - 1 - 8;
+
+/// Checks whether the hexadecimal representation of `digest` starts with `zeros` zeroes,
+/// without formatting it.
+fn has_leading_zeros(digest: &[u8; 16], zeros: usize) -> bool {
+    let (bytes, half) = (zeros / 2, zeros % 2 == 1);
+    digest[..bytes].iter().all(|&byte| byte == 0) && (!half || digest[bytes] < 0x10)
+}
+
+/// Writes the decimal digits of `n` to the end of `buffer`, returning them.
+fn digits(mut n: usize, buffer: &mut [u8; 20]) -> &[u8] {
+    let mut start = buffer.len();
+    loop {
+        start -= 1;
+        buffer[start] = b'0' + (n % 10) as u8;
+        n /= 10;
+        if n == 0 {
+            return &buffer[start..];
+        }
+    }
+}
+
+/// Finds the lowest positive number which, appended to `seed`, gives an MD5 hash whose
+/// hexadecimal representation starts with `zeros` zeroes. Uses [`mine_lanes`] for seeds
+/// short enough, and [`mine_scalar`] otherwise.
+pub fn mine(seed: &str, zeros: usize) -> Option<usize> {
+    if seed.len() <= MAX_LANE_SEED {
+        mine_lanes(seed, zeros)
+    } else {
+        mine_scalar(seed, zeros)
+    }
+}
+
+/// Like [`mine`], hashing one candidate at a time. Every job appends the digits of its
+/// candidates to a single buffer holding the seed, so no candidate allocates.
+pub fn mine_scalar(seed: &str, zeros: usize) -> Option<usize> {
+    assert!(zeros <= 32, "an MD5 hash has only 32 hexadecimal digits");
+    (1..usize::MAX)
+        .into_par_iter()
+        .by_uniform_blocks(BLOCK)
+        .map_init(
+            || seed.as_bytes().to_vec(),
+            |message, n| {
+                message.truncate(seed.len());
+                message.extend_from_slice(digits(n, &mut [0; 20]));
+                has_leading_zeros(&md5::compute(&message).0, zeros).then_some(n)
+            },
+        )
+        .find_map_first(|n| n)
+}
+
+/// Like [`mine`], hashing [`LANES`] consecutive candidates side by side, which the compiler
+/// can turn into SIMD instructions.
+///
+/// # Panics
+///
+/// Panics if the seed is longer than [`MAX_LANE_SEED`] bytes.
+pub fn mine_lanes(seed: &str, zeros: usize) -> Option<usize> {
+    assert!(zeros <= 32, "an MD5 hash has only 32 hexadecimal digits");
+    assert!(seed.len() <= MAX_LANE_SEED, "the seed {seed:?} is too long");
+    (0..usize::MAX / LANES)
+        .into_par_iter()
+        .by_uniform_blocks(BLOCK / LANES)
+        .map_init(
+            || Lanes::new(seed.as_bytes()),
+            |lanes, batch| {
+                let first = batch * LANES + 1;
+                let digests = lanes.hash(first);
+                let lane = digests.iter().position(|d| has_leading_zeros(d, zeros))?;
+                Some(first + lane)
+            },
+        )
+        .find_map_first(|n| n)
+}
+
+/// The shift amounts of the 64 operations of MD5.
+const SHIFTS: [u32; 64] = {
+    let rounds = [
+        [7, 12, 17, 22],
+        [5, 9, 14, 20],
+        [4, 11, 16, 23],
+        [6, 10, 15, 21],
+    ];
+    let mut shifts = [0; 64];
+    let mut i = 0;
+    while i < 64 {
+        shifts[i] = rounds[i / 16][i % 4];
+        i += 1;
+    }
+    shifts
+};
+
+/// The additive constants of the 64 operations of MD5, `floor(abs(sin(i + 1)) * 2^32)`.
+#[rustfmt::skip]
+const CONSTANTS: [u32; 64] = [
+    0xd76aa478, 0xe8c7b756, 0x242070db, 0xc1bdceee,
+    0xf57c0faf, 0x4787c62a, 0xa8304613, 0xfd469501,
+    0x698098d8, 0x8b44f7af, 0xffff5bb1, 0x895cd7be,
+    0x6b901122, 0xfd987193, 0xa679438e, 0x49b40821,
+    0xf61e2562, 0xc040b340, 0x265e5a51, 0xe9b6c7aa,
+    0xd62f105d, 0x02441453, 0xd8a1e681, 0xe7d3fbc8,
+    0x21e1cde6, 0xc33707d6, 0xf4d50d87, 0x455a14ed,
+    0xa9e3e905, 0xfcefa3f8, 0x676f02d9, 0x8d2a4c8a,
+    0xfffa3942, 0x8771f681, 0x6d9d6122, 0xfde5380c,
+    0xa4beea44, 0x4bdecfa9, 0xf6bb4b60, 0xbebfbc70,
+    0x289b7ec6, 0xeaa127fa, 0xd4ef3085, 0x04881d05,
+    0xd9d4d039, 0xe6db99e5, 0x1fa27cf8, 0xc4ac5665,
+    0xf4292244, 0x432aff97, 0xab9423a7, 0xfc93a039,
+    0x655b59c3, 0x8f0ccc92, 0xffeff47d, 0x85845dd1,
+    0x6fa87e4f, 0xfe2ce6e0, 0xa3014314, 0x4e0811a1,
+    0xf7537e82, 0xbd3af235, 0x2ad7d2bb, 0xeb86d391,
+];
+
+/// The initial state of MD5.
+const INITIAL: [u32; 4] = [0x67452301, 0xefcdab89, 0x98badcfe, 0x10325476];
+
+/// Runs the 16 operations of an MD5 round starting at operation `first` on all lanes, mixing
+/// in the state with `f` and the word chosen by `g`.
+#[inline(always)]
+fn round(
+    state: &mut [[u32; LANES]; 4],
+    words: &[[u32; LANES]; 16],
+    first: usize,
+    f: impl Fn(u32, u32, u32) -> u32,
+    g: impl Fn(usize) -> usize,
+) {
+    let [a, b, c, d] = state;
+    for i in first..first + 16 {
+        let word = &words[g(i) % 16];
+        for lane in 0..LANES {
+            let (x, y, z) = (b[lane], c[lane], d[lane]);
+            let mixed = f(x, y, z)
+                .wrapping_add(a[lane])
+                .wrapping_add(CONSTANTS[i])
+                .wrapping_add(word[lane]);
+            a[lane] = z;
+            d[lane] = y;
+            c[lane] = x;
+            b[lane] = x.wrapping_add(mixed.rotate_left(SHIFTS[i]));
+        }
+    }
+}
+
+/// Message blocks of [`LANES`] candidates sharing a seed, each padded to a single MD5 block.
+struct Lanes {
+    seed: usize,
+    /// The padded message of every lane, starting with the seed, which is only written once.
+    blocks: [[u8; 64]; LANES],
+}
+
+impl Lanes {
+    fn new(seed: &[u8]) -> Self {
+        let mut blocks = [[0; 64]; LANES];
+        for block in &mut blocks {
+            block[..seed.len()].copy_from_slice(seed);
+        }
+        Self {
+            seed: seed.len(),
+            blocks,
+        }
+    }
+
+    /// Returns the MD5 digests of the seed followed by `first`, `first + 1` and so on.
+    fn hash(&mut self, first: usize) -> [[u8; 16]; LANES] {
+        // The words of every block, transposed so that each word holds one value per lane.
+        let mut words = [[0; LANES]; 16];
+        let mut buffer = [0; 20];
+        for (lane, block) in self.blocks.iter_mut().enumerate() {
+            let digits = digits(first + lane, &mut buffer);
+            let len = self.seed + digits.len();
+            block[self.seed..len].copy_from_slice(digits);
+            block[len] = 0x80;
+            block[len + 1..56].fill(0);
+            block[56..].copy_from_slice(&(8 * len as u64).to_le_bytes());
+            for (word, bytes) in words.iter_mut().zip(block.chunks_exact(4)) {
+                word[lane] = u32::from_le_bytes(bytes.try_into().unwrap());
+            }
+        }
+
+        let mut state = INITIAL.map(|word| [word; LANES]);
+        round(&mut state, &words, 0, |x, y, z| (x & y) | (!x & z), |i| i);
+        round(
+            &mut state,
+            &words,
+            16,
+            |x, y, z| (z & x) | (!z & y),
+            |i| 5 * i + 1,
+        );
+        round(&mut state, &words, 32, |x, y, z| x ^ y ^ z, |i| 3 * i + 5);
+        round(&mut state, &words, 48, |x, y, z| y ^ (x | !z), |i| 7 * i);
+        let [a, b, c, d] = state;
+
+        std::array::from_fn(|lane| {
+            let mut digest = [0; 16];
+            for (i, word) in [a, b, c, d].iter().enumerate() {
+                let word = word[lane].wrapping_add(INITIAL[i]);
+                digest[4 * i..4 * i + 4].copy_from_slice(&word.to_le_bytes());
+            }
+            digest
+        })
+    }
+}
+
+/// Returns the lowest number mining a hash with five leading zeroes.
+pub fn part_one(input: &str) -> Option<usize> {
+    mine(input, 5)
+}
+
+/// Returns the lowest number mining a hash with six leading zeroes.
+pub fn part_two(input: &str) -> Option<usize> {
+    mine(input, 6)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one("abcdef"), Some(609043));
+        assert_eq!(part_one("pqrstuv"), Some(1048970));
+    }
+
+    #[test]
+    fn test_engines() {
+        for zeros in 0..=4 {
+            let expected = (1..).find(|n| {
+                format!("{:x}", md5::compute(format!("abcdef{n}"))).starts_with(&"0".repeat(zeros))
+            });
+            assert_eq!(mine_scalar("abcdef", zeros), expected);
+            assert_eq!(mine_lanes("abcdef", zeros), expected);
+        }
+        assert_eq!(mine_scalar("pqrstuv", 5), Some(1048970));
+
+        let long = "a seed too long to fit into a single block";
+        assert!(long.len() > MAX_LANE_SEED);
+        assert_eq!(mine(long, 3), mine_scalar(long, 3));
+    }
+
+    #[test]
+    fn test_lanes_match_md5() {
+        for seed in ["", "abcdef", &"x".repeat(MAX_LANE_SEED)] {
+            let mut lanes = Lanes::new(seed.as_bytes());
+            for first in [1, 95, 999_999, usize::MAX - LANES] {
+                for (lane, digest) in lanes.hash(first).iter().enumerate() {
+                    let message = format!("{seed}{}", first + lane);
+                    assert_eq!(*digest, md5::compute(&message).0, "{message}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_leading_zeros() {
+        let mut digest = [0xff; 16];
+        digest[..2].fill(0);
+        digest[2] = 0x0f;
+        assert!(has_leading_zeros(&digest, 5));
+        assert!(!has_leading_zeros(&digest, 6));
+        assert!(has_leading_zeros(&[0; 16], 32));
+        assert!(has_leading_zeros(&[0xff; 16], 0));
+        assert_eq!(digits(0, &mut [0; 20]), b"0");
+        assert_eq!(
+            digits(usize::MAX, &mut [0; 20]),
+            usize::MAX.to_string().as_bytes()
+        );
+    }
+}
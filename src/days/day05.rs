@@ -0,0 +1,72 @@
+//! Day 5: Doesn't He Have Intern-Elves For This?
+
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(5);
+
+fn is_vowel(ch: &u8) -> bool {
+    matches!(ch, b'a' | b'e' | b'i' | b'o' | b'u')
+}
+
+fn is_naughy_pair(token: &[u8]) -> bool {
+    matches!(token, b"ab" | b"cd" | b"pq" | b"xy")
+}
+
+/// Checks whether a string is nice by the first set of rules: at least three vowels,
+/// a letter appearing twice in a row, and none of `ab`, `cd`, `pq` or `xy`.
+pub fn is_nice_v0(string: &str) -> bool {
+    let bytes = string.as_bytes();
+
+    let enough_vowels = bytes.iter().copied().filter(is_vowel).count() >= 3;
+    let twice_in_row = bytes.windows(2).any(|window| window[1] == window[0]);
+    let naughty_pair = bytes.windows(2).any(is_naughy_pair);
+
+    enough_vowels && twice_in_row && !naughty_pair
+}
+
+/// Checks whether a string is nice by the second set of rules: a pair of letters appearing
+/// twice without overlapping, and a letter repeating with exactly one letter in between.
+pub fn is_nice_v1(string: &str) -> bool {
+    let bytes = string.as_bytes();
+
+    let repeated_pair = bytes
+        .windows(2)
+        .enumerate()
+        .any(|(offset, lhs)| bytes[offset + 2..].windows(2).any(|rhs| lhs == rhs));
+    let repeated_letter = bytes.windows(3).any(|window| window[2] == window[0]);
+
+    repeated_pair && repeated_letter
+}
+
+/// Returns the number of nice strings by the first set of rules.
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(input.lines().filter(|word| is_nice_v0(word)).count())
+}
+
+/// Returns the number of nice strings by the second set of rules.
+pub fn part_two(input: &str) -> Option<usize> {
+    Some(input.lines().filter(|word| is_nice_v1(word)).count())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert!(is_nice_v0("ugknbfddgicrmopn"));
+        assert!(is_nice_v0("aaa"));
+        assert!(!is_nice_v0("jchzalrnumimnmhp"));
+        assert!(!is_nice_v0("haegwjzuvuyypxyu"));
+        assert!(!is_nice_v0("dvszwmarrgswjxmb"));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert!(is_nice_v1("qjhvhtzxzqqjkmpb"));
+        assert!(is_nice_v1("xxyxx"));
+        assert!(!is_nice_v1("uurcxstgmygtbstg"));
+        assert!(!is_nice_v1("ieodomkazucvgmuy"));
+    }
+}
@@ -0,0 +1,353 @@
+//! Day 6: Probably a Fire Hazard
+
+use crate::grid::Grid;
+use crate::image::Image;
+use crate::parse::{self, FromField, Line, ParseError};
+use ndarray::ArrayViewMut2;
+use std::ops::RangeInclusive;
+
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(6);
+
+/// What an instruction does to the lights in its rectangle.
+#[derive(Clone, Copy, Debug)]
+pub enum Op {
+    /// `turn off`
+    Off,
+    /// `turn on`
+    On,
+    /// `toggle`
+    Toggle,
+}
+
+impl FromField<'_> for Op {
+    fn from_field(field: &str) -> Result<Self, String> {
+        match field {
+            "turn off" => Ok(Op::Off),
+            "turn on" => Ok(Op::On),
+            "toggle" => Ok(Op::Toggle),
+            _ => Err(format!("invalid op {field:?}")),
+        }
+    }
+}
+
+/// An instruction like `turn on 0,0 through 999,999`, covering an inclusive rectangle.
+pub struct Instr {
+    op: Op,
+    x_range: RangeInclusive<usize>,
+    y_range: RangeInclusive<usize>,
+}
+
+impl TryFrom<Line<'_>> for Instr {
+    type Error = ParseError;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        let (op, x_start, y_start, x_stop, y_stop) =
+            crate::parse!(line, "{op} {x0},{y0} through {x1},{y1}")?;
+        if x_start > x_stop || y_start > y_stop {
+            return Err(line.error("expected the first corner to be the top left"));
+        }
+        Ok(Self {
+            op,
+            x_range: x_start..=x_stop,
+            y_range: y_start..=y_stop,
+        })
+    }
+}
+
+/// The 1000x1000 grid of lights, each with a brightness.
+pub struct Lights {
+    map: Grid<usize>,
+}
+
+impl Default for Lights {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Lights {
+    /// Creates a grid with all lights off.
+    pub fn new() -> Self {
+        let map = Grid::new(1000, 1000, 0);
+        Self { map }
+    }
+
+    /// Applies an instruction with the lights only being on or off.
+    pub fn apply_v0(&mut self, instr: &Instr) {
+        let region = self
+            .map
+            .region_mut(instr.x_range.clone(), instr.y_range.clone());
+        switch(region, instr.op);
+    }
+
+    /// Applies an instruction with the brightness being increased or decreased.
+    pub fn apply_v1(&mut self, instr: &Instr) {
+        let region = self
+            .map
+            .region_mut(instr.x_range.clone(), instr.y_range.clone());
+        dim(region, instr.op);
+    }
+
+    /// The number of lights which are on.
+    pub fn lit(&self) -> usize {
+        self.map.iter().filter(|val| **val > 0).count()
+    }
+
+    /// The total brightness of all lights.
+    pub fn brightness(&self) -> usize {
+        self.map.iter().sum()
+    }
+
+    /// Draws the brightness of every light as a gray, with the brightest lights white.
+    pub fn image(&self) -> Image {
+        let max = self.map.iter().copied().max().unwrap_or(0).max(1);
+        Image::from_grid(&self.map, |&brightness| (brightness * 255 / max) as u8)
+    }
+}
+
+fn switch(mut region: ArrayViewMut2<usize>, op: Op) {
+    match op {
+        Op::Off => region.fill(0),
+        Op::On => region.fill(1),
+        Op::Toggle => region.map_inplace(|b| *b ^= 1),
+    }
+}
+
+fn dim(mut region: ArrayViewMut2<usize>, op: Op) {
+    match op {
+        Op::Off => region.map_inplace(|b| *b = b.saturating_sub(1)),
+        Op::On => region.map_inplace(|b| *b += 1),
+        Op::Toggle => region.map_inplace(|b| *b += 2),
+    }
+}
+
+/// The start and the exclusive end of a range of lights.
+fn exclusive(range: &RangeInclusive<usize>) -> [u128; 2] {
+    [*range.start() as u128, *range.end() as u128 + 1]
+}
+
+/// A grid of lights of any size, which only stores one brightness for every rectangle between
+/// the corners of a fixed set of instructions, since all lights in it always change together.
+/// Memory and time depend on the number of instructions rather than on the size of the grid.
+pub struct CompressedLights {
+    /// The first column of every rectangle, followed by the end of the last one. The end lies
+    /// beyond `usize::MAX` for lights in the last column, so the bounds are wider.
+    xs: Vec<u128>,
+    /// The first row of every rectangle, followed by the end of the last one.
+    ys: Vec<u128>,
+    map: Grid<usize>,
+}
+
+impl CompressedLights {
+    /// Creates a grid with all lights off, split along the corners of `instrs`.
+    pub fn new(instrs: &[Instr]) -> Self {
+        let bounds = |range: fn(&Instr) -> &RangeInclusive<usize>| {
+            let mut bounds: Vec<_> = instrs
+                .iter()
+                .flat_map(|instr| exclusive(range(instr)))
+                .collect();
+            bounds.sort_unstable();
+            bounds.dedup();
+            bounds
+        };
+        let xs = bounds(|instr| &instr.x_range);
+        let ys = bounds(|instr| &instr.y_range);
+        let map = Grid::new(xs.len().saturating_sub(1), ys.len().saturating_sub(1), 0);
+        Self { xs, ys, map }
+    }
+
+    /// The rectangles covered by an instruction, as column and row ranges of the map.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the instruction was not passed to [`CompressedLights::new`].
+    fn region(&mut self, instr: &Instr) -> ArrayViewMut2<'_, usize> {
+        let compress = |bounds: &[u128], range: &RangeInclusive<usize>| {
+            let [start, end] = exclusive(range);
+            let (start, end) = (bounds.binary_search(&start), bounds.binary_search(&end));
+            match (start, end) {
+                (Ok(start), Ok(end)) => start..=end - 1,
+                _ => panic!("the instruction was not used to create the grid"),
+            }
+        };
+        let xs = compress(&self.xs, &instr.x_range);
+        let ys = compress(&self.ys, &instr.y_range);
+        self.map.region_mut(xs, ys)
+    }
+
+    /// Applies an instruction with the lights only being on or off.
+    pub fn apply_v0(&mut self, instr: &Instr) {
+        switch(self.region(instr), instr.op);
+    }
+
+    /// Applies an instruction with the brightness being increased or decreased.
+    pub fn apply_v1(&mut self, instr: &Instr) {
+        dim(self.region(instr), instr.op);
+    }
+
+    /// Iterates over the number of lights in every rectangle and their brightness.
+    fn rectangles(&self) -> impl Iterator<Item = (u128, u128)> + '_ {
+        self.map.indexed_iter().map(|((x, y), &brightness)| {
+            let width = self.xs[x + 1] - self.xs[x];
+            let height = self.ys[y + 1] - self.ys[y];
+            (width * height, brightness as u128)
+        })
+    }
+
+    /// The number of lights which are on, which may not fit into a `usize`.
+    pub fn lit(&self) -> u128 {
+        self.rectangles()
+            .filter(|&(_, brightness)| brightness > 0)
+            .map(|(area, _)| area)
+            .sum()
+    }
+
+    /// The total brightness of all lights.
+    pub fn brightness(&self) -> u128 {
+        self.rectangles()
+            .map(|(area, brightness)| area * brightness)
+            .sum()
+    }
+}
+
+/// Returns the number of lights which are lit after following the instructions.
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let mut lights = Lights::new();
+    for line in parse::lines(input) {
+        lights.apply_v0(&line.try_into()?);
+    }
+    Ok(lights.lit())
+}
+
+/// Returns the total brightness after following the instructions with the Elvish meaning.
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let mut lights = Lights::new();
+    for line in parse::lines(input) {
+        lights.apply_v1(&line.try_into()?);
+    }
+    Ok(lights.brightness())
+}
+
+/// Draws the brightness of the lights after following the instructions with the Elvish meaning.
+pub fn visualize(input: &str) -> Result<Image, ParseError> {
+    let mut lights = Lights::new();
+    for line in parse::lines(input) {
+        lights.apply_v1(&line.try_into()?);
+    }
+    Ok(lights.image())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Ok(998996));
+        assert_eq!(part_one("turn on 0,0 through 999,999"), Ok(1000 * 1000));
+        assert_eq!(part_one("toggle 0,0 through 999,0"), Ok(1000));
+        assert_eq!(part_one("turn off 499,499 through 500,500"), Ok(0));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two("turn on 0,0 through 0,0"), Ok(1));
+        assert_eq!(part_two("toggle 0,0 through 999,999"), Ok(2000000));
+    }
+
+    fn instructions(input: &str) -> Vec<Instr> {
+        parse::lines(input)
+            .map(|line| line.try_into().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_compressed() {
+        // A small linear congruential generator keeps the instructions deterministic.
+        let mut state = 6_u64;
+        let mut next = |bound: u64| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) % bound
+        };
+        let mut input = String::new();
+        for _ in 0..60 {
+            let op = ["turn on", "turn off", "toggle"][next(3) as usize];
+            let (x0, y0) = (next(1000), next(1000));
+            let (x1, y1) = (x0 + next(1000 - x0), y0 + next(1000 - y0));
+            input.push_str(&format!("{op} {x0},{y0} through {x1},{y1}\n"));
+        }
+        let instrs = instructions(&input);
+
+        let (mut lights, mut compressed) = (Lights::new(), CompressedLights::new(&instrs));
+        for instr in &instrs {
+            lights.apply_v0(instr);
+            compressed.apply_v0(instr);
+        }
+        assert_eq!(compressed.lit(), lights.lit() as u128);
+        assert_eq!(compressed.brightness(), lights.brightness() as u128);
+
+        let (mut lights, mut compressed) = (Lights::new(), CompressedLights::new(&instrs));
+        for instr in &instrs {
+            lights.apply_v1(instr);
+            compressed.apply_v1(instr);
+        }
+        assert_eq!(compressed.lit(), lights.lit() as u128);
+        assert_eq!(compressed.brightness(), lights.brightness() as u128);
+    }
+
+    #[test]
+    fn test_compressed_huge() {
+        let instrs = instructions(
+            "turn on 0,0 through 999999999,999999999\n\
+             toggle 1000,1000 through 1999,2999\n\
+             turn off 999999999,0 through 999999999,999999999",
+        );
+        let mut lights = CompressedLights::new(&instrs);
+        instrs.iter().for_each(|instr| lights.apply_v0(instr));
+        assert_eq!(lights.lit(), 1_000_000_000 * 999_999_999 - 2_000_000);
+
+        let mut lights = CompressedLights::new(&instrs);
+        instrs.iter().for_each(|instr| lights.apply_v1(instr));
+        assert_eq!(lights.lit(), 1_000_000_000 * 999_999_999);
+        assert_eq!(
+            lights.brightness(),
+            1_000_000_000 * 999_999_999 + 2 * 2_000_000
+        );
+    }
+
+    #[test]
+    fn test_compressed_edges() {
+        let max = usize::MAX;
+        let instrs = instructions(&format!(
+            "turn on {max},0 through {max},9\ntoggle 0,{max} through {max},{max}"
+        ));
+        let mut lights = CompressedLights::new(&instrs);
+        instrs.iter().for_each(|instr| lights.apply_v0(instr));
+        assert_eq!(lights.lit(), max as u128 + 1 + 10);
+
+        let mut lights = CompressedLights::new(&instrs);
+        instrs.iter().for_each(|instr| lights.apply_v1(instr));
+        assert_eq!(lights.brightness(), 2 * (max as u128 + 1) + 10);
+    }
+
+    #[test]
+    fn test_visualize() {
+        let image = visualize("turn on 0,0 through 9,9\ntoggle 0,0 through 0,0").unwrap();
+        assert_eq!((image.width(), image.height()), (1000, 1000));
+        assert_eq!(image.pixel(0, 0), 255);
+        assert_eq!(image.pixel(9, 9), 85);
+        assert_eq!(image.pixel(10, 0), 0);
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = part_one("turn on 0,0 through 999,999\ntoggle 0,0 throug 9,9").unwrap_err();
+        assert_eq!((err.line, err.column), (2, 18));
+    }
+}
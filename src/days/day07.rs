@@ -0,0 +1,693 @@
+//! Day 7: Some Assembly Required
+
+use std::collections::HashMap;
+use std::fmt::{self, Display};
+
+use crate::graph::toposort;
+use crate::parse::{self, Line, ParseError};
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(7);
+
+#[derive(Clone, Copy)]
+enum Var<'i> {
+    Integer(u16),
+    Name(&'i str),
+}
+
+impl Display for Var<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Var::Integer(value) => write!(f, "{value}"),
+            Var::Name(name) => f.write_str(name),
+        }
+    }
+}
+
+enum Op<'i> {
+    Assign {
+        inp: Var<'i>,
+        out: &'i str,
+    },
+    Not {
+        inp: Var<'i>,
+        out: &'i str,
+    },
+    And {
+        lhs: Var<'i>,
+        rhs: Var<'i>,
+        out: &'i str,
+    },
+    Or {
+        lhs: Var<'i>,
+        rhs: Var<'i>,
+        out: &'i str,
+    },
+    Lshift {
+        lhs: Var<'i>,
+        rhs: Var<'i>,
+        out: &'i str,
+    },
+    Rshift {
+        lhs: Var<'i>,
+        rhs: Var<'i>,
+        out: &'i str,
+    },
+}
+
+impl<'i> Op<'i> {
+    fn out(&self) -> &'i str {
+        match self {
+            Self::Assign { out, .. }
+            | Self::Not { out, .. }
+            | Self::And { out, .. }
+            | Self::Or { out, .. }
+            | Self::Lshift { out, .. }
+            | Self::Rshift { out, .. } => out,
+        }
+    }
+
+    /// The kind of gate and its operands, with `rhs` set for binary gates only.
+    fn operands(&self) -> (Gate, Var<'i>, Option<Var<'i>>) {
+        match *self {
+            Self::Assign { inp, out: _ } => (Gate::Assign, inp, None),
+            Self::Not { inp, out: _ } => (Gate::Not, inp, None),
+            Self::And { lhs, rhs, out: _ } => (Gate::And, lhs, Some(rhs)),
+            Self::Or { lhs, rhs, out: _ } => (Gate::Or, lhs, Some(rhs)),
+            Self::Lshift { lhs, rhs, out: _ } => (Gate::Lshift, lhs, Some(rhs)),
+            Self::Rshift { lhs, rhs, out: _ } => (Gate::Rshift, lhs, Some(rhs)),
+        }
+    }
+
+    fn inputs(&self) -> impl Iterator<Item = &'i str> {
+        let (_, lhs, rhs) = self.operands();
+        [Some(lhs), rhs]
+            .into_iter()
+            .flatten()
+            .filter_map(|var| match var {
+                Var::Name(name) => Some(name),
+                Var::Integer(_) => None,
+            })
+    }
+}
+
+/// Parses a signal, which is either a number or the name of a wire.
+fn var<'i>(line: &Line<'i>, token: &'i str) -> Result<Var<'i>, ParseError> {
+    if token.starts_with(|ch: char| ch.is_ascii_digit()) {
+        line.parse(token).map(Var::Integer)
+    } else {
+        wire(line, token).map(Var::Name)
+    }
+}
+
+/// Parses the name of a wire, which consists of lowercase letters.
+fn wire<'i>(line: &Line<'i>, token: &'i str) -> Result<&'i str, ParseError> {
+    if !token.is_empty() && token.bytes().all(|b| b.is_ascii_lowercase()) {
+        Ok(token)
+    } else {
+        Err(line.error_at(token, "invalid wire name"))
+    }
+}
+
+impl<'i> TryFrom<Line<'i>> for Op<'i> {
+    type Error = ParseError;
+
+    fn try_from(line: Line<'i>) -> Result<Self, Self::Error> {
+        let (op_str, out) = line
+            .text
+            .split_once(" -> ")
+            .ok_or_else(|| line.error("expected \"<gate> -> <wire>\""))?;
+        let out = wire(&line, out)?;
+        let tokens: Vec<_> = op_str.split_ascii_whitespace().collect();
+        Ok(match *tokens.as_slice() {
+            [inp] => Self::Assign {
+                inp: var(&line, inp)?,
+                out,
+            },
+            ["NOT", inp] => Self::Not {
+                inp: var(&line, inp)?,
+                out,
+            },
+            [lhs, "AND", rhs] => Self::And {
+                lhs: var(&line, lhs)?,
+                rhs: var(&line, rhs)?,
+                out,
+            },
+            [lhs, "OR", rhs] => Self::Or {
+                lhs: var(&line, lhs)?,
+                rhs: var(&line, rhs)?,
+                out,
+            },
+            [lhs, "LSHIFT", rhs] => Self::Lshift {
+                lhs: var(&line, lhs)?,
+                rhs: var(&line, rhs)?,
+                out,
+            },
+            [lhs, "RSHIFT", rhs] => Self::Rshift {
+                lhs: var(&line, lhs)?,
+                rhs: var(&line, rhs)?,
+                out,
+            },
+            _ => return Err(line.error_at(op_str, "invalid gate")),
+        })
+    }
+}
+
+/// Shows the gate as in the instructions, without its output, e.g. `x AND y`.
+impl Display for Op<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Op::Assign { inp, out: _ } => write!(f, "{inp}"),
+            Op::Not { inp, out: _ } => write!(f, "NOT {inp}"),
+            Op::And { lhs, rhs, out: _ } => write!(f, "{lhs} AND {rhs}"),
+            Op::Or { lhs, rhs, out: _ } => write!(f, "{lhs} OR {rhs}"),
+            Op::Lshift { lhs, rhs, out: _ } => write!(f, "{lhs} LSHIFT {rhs}"),
+            Op::Rshift { lhs, rhs, out: _ } => write!(f, "{lhs} RSHIFT {rhs}"),
+        }
+    }
+}
+
+/// An error which prevents a circuit from being evaluated.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CircuitError {
+    /// The instructions could not be parsed.
+    Parse(ParseError),
+    /// A gate reads a wire which is neither driven by a gate nor overridden.
+    Undefined {
+        /// The wire without signal.
+        wire: String,
+        /// The wire driven by the gate reading it.
+        reader: String,
+    },
+    /// The signals on these wires depend on each other, in this order.
+    Cycle(Vec<String>),
+    /// A wire was requested or overridden which is not part of the circuit.
+    UnknownWire(String),
+}
+
+impl std::error::Error for CircuitError {}
+
+impl Display for CircuitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CircuitError::Parse(err) => write!(f, "{err}"),
+            CircuitError::Undefined { wire, reader } => {
+                write!(
+                    f,
+                    "wire {wire} is read by the gate of {reader}, but has no signal"
+                )
+            }
+            CircuitError::Cycle(wires) => {
+                write!(f, "the signal depends on itself: ")?;
+                for wire in wires {
+                    write!(f, "{wire} -> ")?;
+                }
+                write!(f, "{}", wires.first().map_or("", String::as_str))
+            }
+            CircuitError::UnknownWire(wire) => write!(f, "there is no wire {wire}"),
+        }
+    }
+}
+
+impl From<ParseError> for CircuitError {
+    fn from(err: ParseError) -> Self {
+        CircuitError::Parse(err)
+    }
+}
+
+/// A circuit of logic gates, each of which drives a single wire.
+pub struct Circuit<'i> {
+    ops: Vec<Op<'i>>,
+    /// All wires, in order of their first appearance.
+    wires: Vec<&'i str>,
+    /// The index of the gate driving each wire.
+    drivers: HashMap<&'i str, usize>,
+    /// The wires driven by gates which read each wire.
+    fanout: HashMap<&'i str, Vec<&'i str>>,
+}
+
+impl<'i> Circuit<'i> {
+    /// Parses one gate per line. A wire may only be driven by a single gate.
+    pub fn parse(input: &'i str) -> Result<Self, ParseError> {
+        let mut circuit = Circuit {
+            ops: vec![],
+            wires: vec![],
+            drivers: HashMap::new(),
+            fanout: HashMap::new(),
+        };
+        let mut lines = HashMap::new();
+        for line in parse::lines(input) {
+            let op = Op::try_from(line)?;
+            if let Some(previous) = lines.insert(op.out(), line.number) {
+                let message = format!("wire {} is already driven on line {previous}", op.out());
+                return Err(line.error(message));
+            }
+            circuit.add(op);
+        }
+        Ok(circuit)
+    }
+
+    fn add(&mut self, op: Op<'i>) {
+        for wire in op.inputs() {
+            self.discover(wire);
+            self.fanout.entry(wire).or_default().push(op.out());
+        }
+        self.discover(op.out());
+        self.drivers.insert(op.out(), self.ops.len());
+        self.ops.push(op);
+    }
+
+    fn discover(&mut self, wire: &'i str) {
+        if !self.drivers.contains_key(wire) && !self.fanout.contains_key(wire) {
+            self.wires.push(wire);
+        }
+    }
+
+    /// Returns all wires, in the order they first appear in the instructions.
+    pub fn wires(&self) -> &[&'i str] {
+        &self.wires
+    }
+
+    /// Computes the signal on every wire.
+    pub fn evaluate(&self) -> Result<Signals<'i>, CircuitError> {
+        self.evaluate_with(&[])
+    }
+
+    /// Computes the signal on every wire, with the given wires carrying fixed signals instead
+    /// of the output of their gates.
+    pub fn evaluate_with(&self, overrides: &[(&str, u16)]) -> Result<Signals<'i>, CircuitError> {
+        let mut fixed = HashMap::new();
+        for &(wire, signal) in overrides {
+            fixed.insert(self.find(wire)?, signal);
+        }
+
+        let mut signals = Signals::default();
+        for wire in self.order(|wire| fixed.contains_key(wire))? {
+            if let Some(&signal) = fixed.get(wire) {
+                signals.wires.insert(wire, signal);
+            } else if let Some(&driver) = self.drivers.get(wire) {
+                // Every input was ordered before its reader and checked to be defined.
+                signals.apply(&self.ops[driver]).unwrap();
+            }
+        }
+        Ok(signals)
+    }
+
+    /// Compiles the circuit into straight-line code, for evaluating it many times with
+    /// different signals on the `inputs` wires, which replace the output of their gates.
+    pub fn compile(&self, inputs: &[&str]) -> Result<Compiled<'i>, CircuitError> {
+        let inputs = inputs
+            .iter()
+            .map(|wire| self.find(wire))
+            .collect::<Result<Vec<_>, _>>()?;
+        let order = self.order(|wire| inputs.contains(&wire))?;
+
+        let slots: HashMap<_, _> = self
+            .wires
+            .iter()
+            .enumerate()
+            .map(|(i, &w)| (w, i))
+            .collect();
+        let mut initial = vec![0; self.wires.len()];
+        let mut slot = |var| match var {
+            Var::Name(name) => slots[name],
+            Var::Integer(value) => {
+                initial.push(value);
+                initial.len() - 1
+            }
+        };
+
+        let mut program = vec![];
+        for wire in order.into_iter().filter(|wire| !inputs.contains(wire)) {
+            let Some(&driver) = self.drivers.get(wire) else {
+                continue;
+            };
+            let (gate, lhs, rhs) = self.ops[driver].operands();
+            let lhs = slot(lhs);
+            let rhs = rhs.map_or(lhs, &mut slot);
+            program.push(Instr {
+                gate,
+                lhs,
+                rhs,
+                out: slots[wire],
+            });
+        }
+
+        Ok(Compiled {
+            inputs: inputs.iter().map(|wire| slots[wire]).collect(),
+            slots,
+            initial,
+            program,
+        })
+    }
+
+    /// Looks up a wire of the circuit by name.
+    fn find(&self, wire: &str) -> Result<&'i str, CircuitError> {
+        self.wires
+            .iter()
+            .copied()
+            .find(|w| *w == wire)
+            .ok_or_else(|| CircuitError::UnknownWire(wire.to_string()))
+    }
+
+    /// Orders the wires so that every gate comes after its inputs, where the `fixed` wires
+    /// don't depend on their inputs. Checks that every other gate only reads defined wires.
+    fn order(&self, fixed: impl Fn(&str) -> bool) -> Result<Vec<&'i str>, CircuitError> {
+        for op in self.ops.iter().filter(|op| !fixed(op.out())) {
+            if let Some(wire) = op
+                .inputs()
+                .find(|wire| !self.drivers.contains_key(wire) && !fixed(wire))
+            {
+                return Err(CircuitError::Undefined {
+                    wire: wire.to_string(),
+                    reader: op.out().to_string(),
+                });
+            }
+        }
+
+        // Overridden wires don't depend on their inputs, which also breaks cycles through them.
+        toposort(self.wires.iter().copied(), |wire| {
+            let readers = self.fanout.get(wire).into_iter().flatten();
+            readers.copied().filter(|out| !fixed(out))
+        })
+        .map_err(|cycle| CircuitError::Cycle(cycle.0.iter().map(|w| w.to_string()).collect()))
+    }
+
+    /// Renders the circuit as a Graphviz DOT graph, with a node for every wire labelled with
+    /// the gate driving it, and an edge from every wire to the gates reading it.
+    pub fn to_dot(&self) -> String {
+        // Every node ID is quoted, since wires may be named like DOT keywords such as `node`.
+        let mut dot = String::from("digraph circuit {\n");
+        for wire in &self.wires {
+            match self.drivers.get(wire) {
+                Some(&driver) => {
+                    let op = &self.ops[driver];
+                    dot.push_str(&format!("    {wire:?} [label=\"{wire}\\n{op}\"];\n"));
+                }
+                None => dot.push_str(&format!("    {wire:?} [shape=box];\n")),
+            }
+        }
+        for op in &self.ops {
+            for wire in op.inputs() {
+                dot.push_str(&format!("    {wire:?} -> {:?};\n", op.out()));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum Gate {
+    Assign,
+    Not,
+    And,
+    Or,
+    Lshift,
+    Rshift,
+}
+
+/// A gate reading and writing slots, where binary gates read both `lhs` and `rhs`.
+#[derive(Clone, Copy, Debug)]
+struct Instr {
+    gate: Gate,
+    lhs: usize,
+    rhs: usize,
+    out: usize,
+}
+
+/// A [`Circuit`] compiled to a list of gates in evaluation order, with every wire and constant
+/// interned to a slot, so that evaluating it is a single pass over the slots without hashing.
+#[derive(Clone, Debug)]
+pub struct Compiled<'i> {
+    /// The slot of each wire, in the order of [`Circuit::wires`].
+    slots: HashMap<&'i str, usize>,
+    /// The slots of the input wires.
+    inputs: Vec<usize>,
+    /// The slots before evaluation, holding the constants after the wires.
+    initial: Vec<u16>,
+    program: Vec<Instr>,
+}
+
+impl<'i> Compiled<'i> {
+    /// Computes the signal on every slot, with one signal for each input wire, in the order
+    /// they were passed to [`Circuit::compile`].
+    pub fn evaluate(&self, inputs: &[u16]) -> Vec<u16> {
+        let mut slots = vec![];
+        self.evaluate_into(inputs, &mut slots);
+        slots
+    }
+
+    /// Like [`Compiled::evaluate`], but reuses the allocation of `slots`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number of signals does not match the number of input wires.
+    pub fn evaluate_into(&self, inputs: &[u16], slots: &mut Vec<u16>) {
+        assert_eq!(inputs.len(), self.inputs.len(), "wrong number of inputs");
+        slots.clone_from(&self.initial);
+        for (&slot, &signal) in self.inputs.iter().zip(inputs) {
+            slots[slot] = signal;
+        }
+        for instr in &self.program {
+            let (lhs, rhs) = (slots[instr.lhs], slots[instr.rhs]);
+            slots[instr.out] = match instr.gate {
+                Gate::Assign => lhs,
+                Gate::Not => !lhs,
+                Gate::And => lhs & rhs,
+                Gate::Or => lhs | rhs,
+                Gate::Lshift => lhs.checked_shl(rhs.into()).unwrap_or(0),
+                Gate::Rshift => lhs.checked_shr(rhs.into()).unwrap_or(0),
+            };
+        }
+    }
+
+    /// The slot holding the signal of a wire, if the wire exists.
+    pub fn slot(&self, wire: &str) -> Option<usize> {
+        self.slots.get(wire).copied()
+    }
+
+    /// Looks up the signals on all wires from the slots computed by [`Compiled::evaluate`].
+    pub fn signals(&self, slots: &[u16]) -> Signals<'i> {
+        let wires = self.slots.iter().map(|(&w, &slot)| (w, slots[slot]));
+        Signals {
+            wires: wires.collect(),
+        }
+    }
+}
+
+/// The signals on all wires of a [`Circuit`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Signals<'i> {
+    wires: HashMap<&'i str, u16>,
+}
+
+impl<'i> Signals<'i> {
+    fn apply(&mut self, op: &Op<'i>) -> Option<()> {
+        match op {
+            Op::Assign { inp, out } => {
+                let value = self.get(*inp)?;
+                self.wires.insert(out, value);
+            }
+            Op::Not { inp, out } => {
+                let value = !self.get(*inp)?;
+                self.wires.insert(out, value);
+            }
+            Op::And { lhs, rhs, out } => {
+                let lhs = self.get(*lhs)?;
+                let rhs = self.get(*rhs)?;
+                let value = lhs & rhs;
+                self.wires.insert(out, value);
+            }
+            Op::Or { lhs, rhs, out } => {
+                let lhs = self.get(*lhs)?;
+                let rhs = self.get(*rhs)?;
+                let value = lhs | rhs;
+                self.wires.insert(out, value);
+            }
+            Op::Lshift { lhs, rhs, out } => {
+                let lhs = self.get(*lhs)?;
+                let rhs = self.get(*rhs)?;
+                // Shifting all bits out of a 16-bit signal leaves it empty.
+                let value = lhs.checked_shl(rhs.into()).unwrap_or(0);
+                self.wires.insert(out, value);
+            }
+            Op::Rshift { lhs, rhs, out } => {
+                let lhs = self.get(*lhs)?;
+                let rhs = self.get(*rhs)?;
+                let value = lhs.checked_shr(rhs.into()).unwrap_or(0);
+                self.wires.insert(out, value);
+            }
+        }
+        Some(())
+    }
+
+    /// The signal on a wire, if the wire exists.
+    pub fn signal(&self, wire: &str) -> Option<u16> {
+        self.wires.get(wire).copied()
+    }
+
+    fn get(&self, var: Var) -> Option<u16> {
+        match var {
+            Var::Integer(i) => Some(i),
+            Var::Name(n) => self.wires.get(n).copied(),
+        }
+    }
+}
+
+/// Returns the signal on wire `a`.
+pub fn part_one(input: &str) -> Result<u16, CircuitError> {
+    let signals = Circuit::parse(input)?.evaluate()?;
+    signals
+        .signal("a")
+        .ok_or_else(|| CircuitError::UnknownWire("a".into()))
+}
+
+/// Returns the signal on wire `a` after overriding wire `b` with the signal from part one.
+pub fn part_two(input: &str) -> Result<u16, CircuitError> {
+    let circuit = Circuit::parse(input)?;
+    let a = circuit.evaluate()?.signal("a");
+    let a = a.ok_or_else(|| CircuitError::UnknownWire("a".into()))?;
+    let compiled = circuit.compile(&["b"])?;
+    let slot = compiled.slot("a").unwrap();
+    Ok(compiled.evaluate(&[a])[slot])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = crate::template::read_file("examples", DAY);
+        let signals = Circuit::parse(&input).unwrap().evaluate().unwrap();
+        assert_eq!(signals.signal("d"), Some(72));
+        assert_eq!(signals.signal("e"), Some(507));
+        assert_eq!(signals.signal("f"), Some(492));
+        assert_eq!(signals.signal("g"), Some(114));
+        assert_eq!(signals.signal("h"), Some(65412));
+        assert_eq!(signals.signal("i"), Some(65079));
+        assert_eq!(signals.signal("x"), Some(123));
+        assert_eq!(signals.signal("y"), Some(456));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = "d -> a\n1 -> b\nb LSHIFT 1 -> c\nc OR 1 -> d";
+        assert_eq!(part_one(input), Ok(3));
+        assert_eq!(part_two(input), Ok(7));
+    }
+
+    #[test]
+    fn test_overrides() {
+        let input = crate::template::read_file("examples", DAY);
+        let circuit = Circuit::parse(&input).unwrap();
+        let signals = circuit.evaluate_with(&[("x", 1), ("d", 9)]).unwrap();
+        assert_eq!(signals.signal("x"), Some(1));
+        assert_eq!(signals.signal("d"), Some(9));
+        assert_eq!(signals.signal("e"), Some(457));
+        assert_eq!(signals.signal("h"), Some(65534));
+        assert_eq!(
+            circuit.evaluate_with(&[("z", 1)]).err(),
+            Some(CircuitError::UnknownWire("z".into()))
+        );
+    }
+
+    #[test]
+    fn test_wide_shifts() {
+        let circuit = Circuit::parse("1 -> b\nb LSHIFT b -> a\nb RSHIFT b -> c").unwrap();
+        let compiled = circuit.compile(&["b"]).unwrap();
+        for (b, a, c) in [(1, 2, 0), (15, 1 << 15, 0), (16, 0, 0), (u16::MAX, 0, 0)] {
+            let signals = circuit.evaluate_with(&[("b", b)]).unwrap();
+            assert_eq!(
+                (signals.signal("a"), signals.signal("c")),
+                (Some(a), Some(c))
+            );
+            assert_eq!(compiled.signals(&compiled.evaluate(&[b])), signals);
+        }
+    }
+
+    #[test]
+    fn test_compiled() {
+        let input = crate::template::read_file("examples", DAY);
+        let circuit = Circuit::parse(&input).unwrap();
+        let compiled = circuit.compile(&["x", "d"]).unwrap();
+        let mut slots = vec![];
+        for (x, d) in [(1, 9), (123, 72), (0, 0), (u16::MAX, 12345)] {
+            compiled.evaluate_into(&[x, d], &mut slots);
+            let expected = circuit.evaluate_with(&[("x", x), ("d", d)]).unwrap();
+            assert_eq!(compiled.signals(&slots), expected);
+        }
+        assert_eq!(slots[compiled.slot("h").unwrap()], !u16::MAX);
+
+        let compiled = circuit.compile(&[]).unwrap();
+        let slots = compiled.evaluate(&[]);
+        assert_eq!(compiled.signals(&slots), circuit.evaluate().unwrap());
+        assert_eq!(compiled.slot("z"), None);
+
+        let cyclic = Circuit::parse("b -> a\na AND 1 -> b\nb -> c").unwrap();
+        assert!(matches!(cyclic.compile(&[]), Err(CircuitError::Cycle(_))));
+        let compiled = cyclic.compile(&["b"]).unwrap();
+        assert_eq!(compiled.evaluate(&[3]), [3, 3, 3]);
+        assert_eq!(
+            cyclic.compile(&["z"]).err(),
+            Some(CircuitError::UnknownWire("z".into()))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = part_one("1 -> a\nb -> a").unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("line 2, column 1: wire a is already driven on line 1"));
+
+        let err = part_one("x AND 1 -> a\nx -> y").unwrap_err();
+        assert_eq!(
+            err,
+            CircuitError::Undefined {
+                wire: "x".into(),
+                reader: "a".into()
+            }
+        );
+
+        let cyclic = "b -> a\na AND 1 -> b\nb -> c";
+        let circuit = Circuit::parse(cyclic).unwrap();
+        let err = circuit.evaluate().unwrap_err();
+        assert_eq!(err, CircuitError::Cycle(vec!["b".into(), "a".into()]));
+        assert_eq!(err.to_string(), "the signal depends on itself: b -> a -> b");
+        let signals = circuit.evaluate_with(&[("b", 3)]).unwrap();
+        assert_eq!(signals.signal("c"), Some(3));
+        assert_eq!(signals.signal("a"), Some(3));
+
+        let circuit = Circuit::parse("a AND 1 -> a").unwrap();
+        assert_eq!(circuit.wires(), ["a"]);
+        assert_eq!(
+            circuit.evaluate(),
+            Err(CircuitError::Cycle(vec!["a".into()]))
+        );
+
+        let err = Circuit::parse("1 AND -> a").err().unwrap();
+        assert_eq!((err.column, err.message.as_str()), (1, "invalid gate"));
+        let err = Circuit::parse("70000 -> a").err().unwrap();
+        assert_eq!(err.column, 1);
+    }
+
+    #[test]
+    fn test_dot() {
+        let circuit = Circuit::parse("123 -> x\nNOT x -> node\nx AND y -> d").unwrap();
+        assert_eq!(
+            circuit.to_dot(),
+            "digraph circuit {\n    \
+                \"x\" [label=\"x\\n123\"];\n    \
+                \"node\" [label=\"node\\nNOT x\"];\n    \
+                \"y\" [shape=box];\n    \
+                \"d\" [label=\"d\\nx AND y\"];\n    \
+                \"x\" -> \"node\";\n    \
+                \"x\" -> \"d\";\n    \
+                \"y\" -> \"d\";\n\
+            }\n"
+        );
+    }
+}
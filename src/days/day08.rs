@@ -0,0 +1,79 @@
+//! Day 8: Matchsticks
+
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(8);
+
+fn from_bytes_radix(bytes: &[u8]) -> u8 {
+    u8::from_str_radix(std::str::from_utf8(bytes).unwrap(), 16).unwrap()
+}
+
+/// Decodes a double-quoted string literal with `\\`, `\"` and `\x..` escapes.
+pub fn unescape(string: &[u8]) -> Vec<u8> {
+    // Remove " prefix and suffix
+    let mut string = &string[1..string.len() - 1];
+
+    let mut output = Vec::new();
+    while let Some(pos) = string.iter().position(|b| *b == b'\\') {
+        let (head, tail) = string.split_at(pos);
+        output.extend_from_slice(head);
+        string = match &tail[0..2] {
+            b"\\\\" => {
+                output.push(b'\\');
+                &tail[2..]
+            }
+            b"\\\"" => {
+                output.push(b'\"');
+                &tail[2..]
+            }
+            b"\\x" => {
+                output.push(from_bytes_radix(&tail[2..4]));
+                &tail[4..]
+            }
+            esc => panic!("Invalid escape character: {:?}", esc),
+        };
+    }
+
+    output.extend_from_slice(string);
+    output
+}
+
+/// The number of characters saved by decoding a string literal.
+pub fn calculate_decode(input: &str) -> usize {
+    let bytes = input.as_bytes();
+    bytes.len() - unescape(bytes).len()
+}
+
+/// The number of characters added by encoding a string as a literal.
+pub fn calculate_encode(input: &str) -> usize {
+    // +2 for the " prefix and suffix
+    input.escape_default().count() - input.len() + 2
+}
+
+/// Returns the characters of code minus the characters in memory of all string literals.
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(input.lines().map(calculate_decode).sum())
+}
+
+/// Returns the characters of the re-encoded literals minus those of the original code.
+pub fn part_two(input: &str) -> Option<usize> {
+    Some(input.lines().map(calculate_encode).sum())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(12));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(19));
+    }
+}
@@ -0,0 +1,100 @@
+//! Day 9: All in a Single Night
+
+use std::collections::HashMap;
+
+use crate::template::Day;
+use crate::tsp::{self, Tour};
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(9);
+
+/// The distances between all pairs of locations.
+pub struct Map<'i> {
+    places: Vec<&'i str>,
+    edges: HashMap<(&'i str, &'i str), usize>,
+}
+
+impl<'i> Map<'i> {
+    fn distance(&self, from: usize, to: usize) -> Option<usize> {
+        self.edges
+            .get(&(self.places[from], self.places[to]))
+            .copied()
+    }
+
+    fn route(&self, tour: Tour<usize>) -> (Vec<&'i str>, usize) {
+        let route = tour.order.iter().map(|i| self.places[*i]).collect();
+        (route, tour.cost)
+    }
+
+    /// The shortest route visiting every location once, and its distance.
+    pub fn shortest_path(&self) -> (Vec<&'i str>, usize) {
+        let tour = tsp::shortest_path(self.places.len(), |a, b| self.distance(a, b)).unwrap();
+        self.route(tour)
+    }
+
+    /// The longest route visiting every location once, and its distance.
+    pub fn longest_path(&self) -> (Vec<&'i str>, usize) {
+        let tour = tsp::longest_path(self.places.len(), |a, b| self.distance(a, b)).unwrap();
+        self.route(tour)
+    }
+}
+
+impl<'i> From<&'i str> for Map<'i> {
+    fn from(value: &'i str) -> Self {
+        let mut places = vec![];
+        let mut edges = HashMap::new();
+        for line in value.lines() {
+            let (from, tail) = line.split_once(" to ").unwrap();
+            let (to, value_str) = tail.split_once(" = ").unwrap();
+            let value: usize = value_str.parse().unwrap();
+            for place in [from, to] {
+                if !places.contains(&place) {
+                    places.push(place);
+                }
+            }
+            edges.insert((from, to), value);
+            edges.insert((to, from), value);
+        }
+        Self { places, edges }
+    }
+}
+
+/// Returns the distance of the shortest route.
+pub fn part_one(input: &str) -> Option<usize> {
+    let map: Map = input.into();
+    Some(map.shortest_path().1)
+}
+
+/// Returns the distance of the longest route.
+pub fn part_two(input: &str) -> Option<usize> {
+    let map: Map = input.into();
+    Some(map.longest_path().1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(605));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(982));
+    }
+
+    #[test]
+    fn test_route() {
+        let input = crate::template::read_file("examples", DAY);
+        let map: Map = input.as_str().into();
+        let (mut route, _) = map.shortest_path();
+        if route[0] != "London" {
+            route.reverse();
+        }
+        assert_eq!(route, vec!["London", "Dublin", "Belfast"]);
+    }
+}
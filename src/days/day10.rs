@@ -0,0 +1,45 @@
+//! Day 10: Elves Look, Elves Say
+
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(10);
+
+fn groups(input: &[u8], output: &mut Vec<u8>) {
+    output.clear();
+    let mut current = input[0];
+    let mut total = 0;
+    for n in input {
+        if *n == current {
+            total += 1;
+        } else {
+            output.push(total);
+            output.push(current);
+            current = *n;
+            total = 1;
+        }
+    }
+    output.push(total);
+    output.push(current);
+}
+
+/// Returns the length of the sequence after applying look-and-say `depth + 1` times.
+pub fn lookandsay(string: &str, depth: usize) -> usize {
+    let mut input: Vec<_> = string.as_bytes().iter().map(|b| b - b'0').collect();
+    let mut output = Vec::new();
+    for _ in 0..=depth {
+        groups(&input, &mut output);
+        std::mem::swap(&mut input, &mut output);
+    }
+    output.len()
+}
+
+/// Returns the length of the result after 40 rounds.
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(lookandsay(input, 40))
+}
+
+/// Returns the length of the result after 50 rounds.
+pub fn part_two(input: &str) -> Option<usize> {
+    Some(lookandsay(input, 50))
+}
@@ -0,0 +1,45 @@
+//! Day 12: JSAbacusFramework.io
+
+use serde_json::{Map, Value};
+
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(12);
+
+fn is_red(object: &Map<String, Value>) -> bool {
+    object.values().any(|value| value == "red")
+}
+
+/// Sums all numbers in a JSON document.
+pub fn sum_numbers(json: &Value) -> i64 {
+    match json {
+        Value::Number(n) => n.as_i64().unwrap(),
+        Value::Array(arr) => arr.iter().map(sum_numbers).sum(),
+        Value::Object(m) => m.values().map(sum_numbers).sum(),
+        Value::String(_) | Value::Bool(_) | Value::Null => 0,
+    }
+}
+
+/// Sums all numbers in a JSON document, ignoring objects with a `"red"` value.
+pub fn sum_numbers_without_red(json: &Value) -> i64 {
+    match json {
+        Value::Number(n) => n.as_i64().unwrap(),
+        Value::Array(arr) => arr.iter().map(sum_numbers_without_red).sum(),
+        Value::Object(m) if is_red(m) => 0,
+        Value::Object(m) => m.values().map(sum_numbers_without_red).sum(),
+        Value::String(_) | Value::Bool(_) | Value::Null => 0,
+    }
+}
+
+/// Returns the sum of all numbers in the document.
+pub fn part_one(input: &str) -> Option<i64> {
+    let json: Value = serde_json::from_str(input).unwrap();
+    Some(sum_numbers(&json))
+}
+
+/// Returns the sum of all numbers in the document, ignoring red objects.
+pub fn part_two(input: &str) -> Option<i64> {
+    let json: Value = serde_json::from_str(input).unwrap();
+    Some(sum_numbers_without_red(&json))
+}
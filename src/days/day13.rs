@@ -0,0 +1,131 @@
+//! Day 13: Knights of the Dinner Table
+
+use std::collections::{BTreeSet, HashMap};
+
+use crate::parse::{self, ParseError};
+use crate::template::Day;
+use crate::tsp;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(13);
+
+/// The happiness change of each guest for each possible neighbour.
+pub struct Seating {
+    map: HashMap<String, HashMap<String, isize>>,
+}
+
+impl Seating {
+    /// Adds a neutral guest who neither gains nor loses happiness next to anyone.
+    pub fn include_myself(&mut self) {
+        self.map.entry("Antagonist".to_string()).or_default();
+    }
+
+    /// The happiness change of `guest` when sitting next to `neighbour`, which is zero for
+    /// guests who did not mention each other.
+    fn change(&self, guest: &str, neighbour: &str) -> isize {
+        let changes = self.map.get(guest);
+        changes.and_then(|c| c.get(neighbour)).copied().unwrap_or(0)
+    }
+
+    /// The best circular seating, starting with any guest, and its total happiness change.
+    /// Returns `None` if there are no guests.
+    pub fn optimal(&self) -> Option<(Vec<&str>, isize)> {
+        let neighbours = self.map.values().flat_map(HashMap::keys);
+        let guests: BTreeSet<&str> = self
+            .map
+            .keys()
+            .chain(neighbours)
+            .map(String::as_str)
+            .collect();
+        let guests: Vec<&str> = guests.into_iter().collect();
+        let happiness = |a: usize, b: usize| {
+            let (a, b) = (guests[a], guests[b]);
+            Some(self.change(a, b) + self.change(b, a))
+        };
+        let tour = tsp::longest_cycle(guests.len(), happiness)?;
+        let order = tour.order.iter().map(|i| guests[*i]).collect();
+        Some((order, tour.cost))
+    }
+}
+
+impl TryFrom<&str> for Seating {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut map: HashMap<String, HashMap<String, isize>> = HashMap::new();
+        for line in parse::lines(value) {
+            let [main, modifier, points, neighbor] = crate::parse!(
+                line,
+                "{} would {} {} happiness units by sitting next to {}."
+            )?;
+            let modifier = match modifier {
+                "gain" => 1,
+                "lose" => -1,
+                _ => return Err(line.error_at(modifier, "expected \"gain\" or \"lose\"")),
+            };
+            let points: isize = line.parse(points)?;
+            map.entry(main.to_string())
+                .or_default()
+                .insert(neighbor.to_string(), modifier * points);
+        }
+        Ok(Self { map })
+    }
+}
+
+/// Returns the total happiness change of the optimal seating, which is zero for an empty table.
+pub fn part_one(input: &str) -> Result<isize, ParseError> {
+    let seating = Seating::try_from(input)?;
+    Ok(seating.optimal().map_or(0, |(_, happiness)| happiness))
+}
+
+/// Returns the total happiness change of the optimal seating including yourself.
+pub fn part_two(input: &str) -> Result<isize, ParseError> {
+    let mut seating = Seating::try_from(input)?;
+    seating.include_myself();
+    Ok(seating.optimal().map_or(0, |(_, happiness)| happiness))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Ok(330));
+    }
+
+    #[test]
+    fn test_optimal_order() {
+        let input = crate::template::read_file("examples", DAY);
+        let seating = Seating::try_from(input.as_str()).unwrap();
+        let (order, happiness) = seating.optimal().unwrap();
+        assert_eq!(happiness, 330);
+        let mut guests = order.clone();
+        guests.sort_unstable();
+        assert_eq!(guests, ["Alice", "Bob", "Carol", "David"]);
+        // The happiest table seats Alice and Bob next to each other.
+        let alice = order.iter().position(|g| *g == "Alice").unwrap();
+        let bob = order.iter().position(|g| *g == "Bob").unwrap();
+        assert!(matches!(alice.abs_diff(bob), 1 | 3));
+    }
+
+    #[test]
+    fn test_missing_relations() {
+        let input = "Alice would gain 5 happiness units by sitting next to Bob.\n\
+            Carol would lose 2 happiness units by sitting next to Alice.";
+        let seating = Seating::try_from(input).unwrap();
+        let (order, happiness) = seating.optimal().unwrap();
+        assert_eq!(order.len(), 3);
+        assert_eq!(happiness, 3);
+        assert!(Seating::try_from("").unwrap().optimal().is_none());
+        assert_eq!(part_one(""), Ok(0));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err =
+            part_one("Alice would win 54 happiness units by sitting next to Bob.").unwrap_err();
+        assert_eq!((err.line, err.column), (1, 13));
+    }
+}
@@ -0,0 +1,100 @@
+//! Day 14: Reindeer Olympics
+
+use crate::parse::{self, Line, ParseError};
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(14);
+
+/// A reindeer that alternates between flying and resting.
+pub struct Reindeer {
+    /// Flying speed in km/s.
+    pub speed_at: u32,
+    /// Seconds of flight before resting.
+    pub speed_for: u32,
+    /// Seconds of rest before flying again.
+    pub rest_for: u32,
+}
+
+impl Reindeer {
+    /// Returns the distance covered after `duration` seconds.
+    pub fn travel(&self, duration: u32) -> u32 {
+        let period = self.speed_for + self.rest_for;
+        let interval = self.speed_at * self.speed_for;
+        (duration / period) * interval + (duration % period).min(self.speed_for) * self.speed_at
+    }
+}
+
+impl TryFrom<Line<'_>> for Reindeer {
+    type Error = ParseError;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        let [_, speed_at, speed_for, rest_for] = crate::parse!(
+            line,
+            "{} can fly {} km/s for {} seconds, but then must rest for {} seconds."
+        )?;
+        Ok(Self {
+            speed_at: line.parse(speed_at)?,
+            speed_for: line.parse(speed_for)?,
+            rest_for: line.parse(rest_for)?,
+        })
+    }
+}
+
+/// Parses one reindeer per line.
+pub fn herd(input: &str) -> Result<Vec<Reindeer>, ParseError> {
+    parse::lines(input).map(Reindeer::try_from).collect()
+}
+
+/// Returns the distance of the winning reindeer after `duration` seconds.
+pub fn race(input: &str, duration: u32) -> Result<u32, ParseError> {
+    let reindeer = herd(input)?;
+    Ok(reindeer
+        .iter()
+        .map(|r| r.travel(duration))
+        .max()
+        .unwrap_or_default())
+}
+
+/// Returns the points of the winning reindeer when the leaders score each second.
+pub fn points(input: &str, duration: u32) -> Result<u32, ParseError> {
+    let reindeer = herd(input)?;
+    let mut tally = vec![0; reindeer.len()];
+    for tick in 1..=duration {
+        let distances: Vec<_> = reindeer.iter().map(|r| r.travel(tick)).collect();
+        let leader = *distances.iter().max().unwrap();
+        for (i, dist) in distances.into_iter().enumerate() {
+            if dist == leader {
+                tally[i] += 1;
+            }
+        }
+    }
+    Ok(tally.into_iter().max().unwrap_or_default())
+}
+
+/// Returns the distance of the winning reindeer after 2503 seconds.
+pub fn part_one(input: &str) -> Result<u32, ParseError> {
+    race(input, 2503)
+}
+
+/// Returns the points of the winning reindeer after 2503 seconds.
+pub fn part_two(input: &str) -> Result<u32, ParseError> {
+    points(input, 2503)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = race(&crate::template::read_file("examples", DAY), 1000);
+        assert_eq!(result, Ok(1120));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = points(&crate::template::read_file("examples", DAY), 1000);
+        assert_eq!(result, Ok(689));
+    }
+}
@@ -0,0 +1,61 @@
+//! Day 17: No Such Thing as Too Much
+
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
+use crate::subsets;
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(17);
+
+/// Counts the container combinations holding exactly `target` litres, by container count.
+pub fn all_combinations(input: &str, target: u64) -> HashMap<usize, usize> {
+    let containers: Vec<_> = input.lines().map(|n| n.parse().unwrap()).collect();
+    let mut tally = HashMap::new();
+    subsets::subsets_with_sum(&containers, target, |combination| {
+        *tally.entry(combination.len()).or_default() += 1;
+        ControlFlow::<()>::Continue(())
+    });
+    tally
+}
+
+/// Counts all combinations holding exactly `target` litres.
+pub fn count_combinations(input: &str, target: u64) -> usize {
+    let tally = all_combinations(input, target);
+    tally.values().sum()
+}
+
+/// Counts the combinations holding exactly `target` litres with the fewest containers.
+pub fn count_min_combinations(input: &str, target: u64) -> usize {
+    let tally = all_combinations(input, target);
+    let min_combination = tally.keys().min().unwrap();
+    tally[min_combination]
+}
+
+/// Returns the number of combinations holding 150 litres.
+pub fn part_one(input: &str) -> Option<usize> {
+    Some(count_combinations(input, 150))
+}
+
+/// Returns the number of minimal combinations holding 150 litres.
+pub fn part_two(input: &str) -> Option<usize> {
+    Some(count_min_combinations(input, 150))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = count_combinations(&crate::template::read_file("examples", DAY), 25);
+        assert_eq!(result, 4);
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = count_min_combinations(&crate::template::read_file("examples", DAY), 25);
+        assert_eq!(result, 3);
+    }
+}
@@ -0,0 +1,199 @@
+//! Day 18: Like a GIF For Your Yard
+
+use std::fmt::Display;
+
+use crate::grid::{Grid, GridError};
+use crate::image::{Animation, Image};
+use crate::life::Life;
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(18);
+
+/// The state of a single light.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Light {
+    /// Drawn as `#`.
+    On,
+    /// Drawn as `.`.
+    Off,
+}
+
+impl TryFrom<char> for Light {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '#' => Ok(Light::On),
+            '.' => Ok(Light::Off),
+            _ => Err(value),
+        }
+    }
+}
+
+impl Display for Light {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Light::On => write!(f, "#"),
+            Light::Off => write!(f, "."),
+        }
+    }
+}
+
+/// An animated grid of lights, stepped one light at a time.
+///
+/// The solution uses the bit-packed [`Life`] engine, and this one is kept as a reference.
+pub struct Map {
+    tiles: Grid<Light>,
+    fixed_corners: bool,
+}
+
+impl Map {
+    fn corners(&self) -> [(usize, usize); 4] {
+        let (width, height) = (self.tiles.width(), self.tiles.height());
+        [
+            (0, 0),
+            (width - 1, 0),
+            (0, height - 1),
+            (width - 1, height - 1),
+        ]
+    }
+
+    /// Turns the four corners on and keeps them stuck on from now on.
+    pub fn set_corners(&mut self) {
+        for corner in self.corners() {
+            self.tiles[corner] = Light::On;
+        }
+        self.fixed_corners = true;
+    }
+
+    /// Advances the animation by one step.
+    pub fn tick(&mut self) {
+        let prev = self.tiles.clone();
+        let corners = self.corners();
+        for (pos, light) in self.tiles.indexed_iter_mut() {
+            if self.fixed_corners && corners.contains(&pos) {
+                continue;
+            }
+
+            let counted_on = prev
+                .neighbours8(pos.0, pos.1)
+                .filter(|(_, light)| **light == Light::On)
+                .count();
+
+            *light = match (*light, counted_on) {
+                (Light::On, 2 | 3) | (Light::Off, 3) => Light::On,
+                _ => Light::Off,
+            };
+        }
+    }
+
+    /// Counts the lights that are on.
+    pub fn lights(&self) -> usize {
+        self.tiles.iter().filter(|l| **l == Light::On).count()
+    }
+
+    /// Draws the lights that are on in white and the others in black.
+    pub fn image(&self) -> Image {
+        Image::from_grid(&self.tiles, |light| match light {
+            Light::On => 255,
+            Light::Off => 0,
+        })
+    }
+}
+
+impl From<&str> for Map {
+    fn from(value: &str) -> Self {
+        Self {
+            tiles: value.parse().unwrap(),
+            fixed_corners: false,
+        }
+    }
+}
+
+/// Counts the lights that are on after `steps` steps, optionally with stuck corners.
+/// Returns `None` if the input is not a grid of lights.
+pub fn simulate(input: &str, steps: usize, corners: bool) -> Option<usize> {
+    let mut life: Life = input.parse().ok()?;
+    if corners {
+        for (x, y) in life.corners()? {
+            life.stick(x, y, true);
+        }
+    }
+    for _ in 0..steps {
+        life.step();
+    }
+    Some(life.population())
+}
+
+/// Animates the first 100 steps, with every light drawn as a square of 4 by 4 pixels.
+pub fn visualize(input: &str) -> Result<Animation, GridError> {
+    let mut map = Map {
+        tiles: input.parse()?,
+        fixed_corners: false,
+    };
+    let mut animation = Animation::new(10);
+    for step in 0..=100 {
+        if step > 0 {
+            map.tick();
+        }
+        // Every step of the same map has the same size.
+        animation.push(map.image().scale(4)).unwrap();
+    }
+    Ok(animation)
+}
+
+/// Returns the lights that are on after 100 steps.
+pub fn part_one(input: &str) -> Option<usize> {
+    simulate(input, 100, false)
+}
+
+/// Returns the lights that are on after 100 steps with stuck corners.
+pub fn part_two(input: &str) -> Option<usize> {
+    simulate(input, 100, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = simulate(&crate::template::read_file("examples", DAY), 4, false);
+        assert_eq!(result, Some(4));
+        assert_eq!(part_one("#.\n#"), None);
+    }
+
+    #[test]
+    fn test_visualize() {
+        let animation = visualize(&crate::template::read_file("examples", DAY)).unwrap();
+        let frames = animation.frames();
+        assert_eq!(frames.len(), 101);
+        assert_eq!((frames[0].width(), frames[0].height()), (24, 24));
+        assert_eq!(frames[0].pixel(4, 0), 255);
+        assert_eq!(frames[0].pixel(0, 0), 0);
+        assert!(visualize("#.\n#").is_err());
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = simulate(&crate::template::read_file("examples", DAY), 5, true);
+        assert_eq!(result, Some(17));
+    }
+
+    #[test]
+    fn test_engines_agree() {
+        let input = crate::testing::lights(100, 30, 18);
+
+        for corners in [false, true] {
+            let mut map = Map::from(input.as_str());
+            if corners {
+                map.set_corners();
+            }
+            for steps in 0..20 {
+                assert_eq!(simulate(&input, steps, corners), Some(map.lights()));
+                map.tick();
+            }
+        }
+    }
+}
@@ -0,0 +1,133 @@
+//! Day 19: Medicine for Rudolph
+
+use std::collections::{HashMap, HashSet};
+
+use crate::parse::{self, Line, ParseError};
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(19);
+
+/// The replacement rules, grouped by the element they replace.
+pub struct Replacements {
+    /// The possible replacements of each element.
+    pub lut: HashMap<String, Vec<String>>,
+}
+
+impl TryFrom<&[Line<'_>]> for Replacements {
+    type Error = ParseError;
+
+    fn try_from(lines: &[Line]) -> Result<Self, Self::Error> {
+        let mut lut: HashMap<String, Vec<String>> = HashMap::new();
+        for line in lines {
+            let (from, to): (String, String) = crate::parse!(*line, "{from} => {to}")?;
+            lut.entry(from).or_default().push(to);
+        }
+        Ok(Self { lut })
+    }
+}
+
+/// The replacement rules and the medicine molecule.
+pub struct Input {
+    /// The replacement rules.
+    pub replacements: Replacements,
+    /// The medicine molecule.
+    pub target: String,
+}
+
+impl TryFrom<&str> for Input {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut sections = parse::sections(value);
+        let replacements = sections.next().unwrap_or_default();
+        let replacements = Replacements::try_from(replacements.as_slice())?;
+        let target = match sections.next().as_deref() {
+            Some([target]) => target.text.to_string(),
+            Some([_, extra, ..]) => return Err(extra.error("expected a single molecule")),
+            _ => String::new(),
+        };
+        Ok(Self {
+            replacements,
+            target,
+        })
+    }
+}
+
+/// Returns the number of distinct molecules reachable by a single replacement.
+pub fn part_one(input: &str) -> Result<usize, ParseError> {
+    let Input {
+        replacements,
+        target,
+    } = input.try_into()?;
+
+    let mut molecules = HashSet::new();
+    for (from, to) in &replacements.lut {
+        for (pos, m) in target.match_indices(from) {
+            for token in to {
+                let mut molecule = target.clone();
+                molecule.replace_range(pos..pos + m.len(), token);
+                molecules.insert(molecule);
+            }
+        }
+    }
+
+    Ok(molecules.len())
+}
+
+/// Splits a molecule into its elements, each an uppercase letter followed by lowercase ones.
+fn sequence(molecule: &str) -> Vec<&str> {
+    let mut elements = vec![];
+    let mut start = 0;
+    for (i, ch) in molecule.char_indices().skip(1) {
+        if ch.is_ascii_uppercase() {
+            elements.push(&molecule[start..i]);
+            start = i;
+        }
+    }
+    if start < molecule.len() {
+        elements.push(&molecule[start..]);
+    }
+    elements
+}
+
+/// Returns the fewest steps needed to make the medicine molecule from `e`.
+pub fn part_two(input: &str) -> Result<usize, ParseError> {
+    let Input { target, .. } = input.try_into()?;
+
+    // From https://www.reddit.com/r/adventofcode/comments/3xflz8/comment/cy4etju/?utm_source=share&utm_medium=web3x&utm_name=web3xcss&utm_term=1&utm_content=share_button
+    let molecules = sequence(&target);
+    let rn_or_ar = molecules
+        .iter()
+        .filter(|m| matches!(**m, "Rn" | "Ar"))
+        .count();
+    let y = molecules.iter().filter(|&m| *m == "Y").count();
+    let steps = molecules.len() - rn_or_ar - 2 * y - 1;
+    Ok(steps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Ok(4));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn test_sequence() {
+        assert_eq!(
+            sequence("CRnCaSiRnBSi"),
+            ["C", "Rn", "Ca", "Si", "Rn", "B", "Si"]
+        );
+        assert_eq!(sequence("eHO"), ["e", "H", "O"]);
+    }
+}
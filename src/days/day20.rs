@@ -0,0 +1,95 @@
+//! Day 20: Infinite Elves and Infinite Houses
+
+use crate::math::Sieve;
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(20);
+
+// House n receives presents from the elves numbered by the divisors of n, including elf n itself,
+// so no house beyond `lower_bound / presents per elf`, rounded up, needs to be checked.
+
+/// Returns the first house receiving at least `lower_bound` presents from infinitely busy elves.
+pub fn find_house(lower_bound: usize) -> Option<usize> {
+    let max_house = lower_bound.div_ceil(10);
+    let sieve = Sieve::new(max_house);
+    (1..=max_house).find(|&house| sieve.sigma(house) * 10 >= lower_bound)
+}
+
+/// Returns the first house receiving at least `lower_bound` presents from elves that stop
+/// after 50 houses.
+pub fn find_house_lazy(lower_bound: usize) -> Option<usize> {
+    let max_house = lower_bound.div_ceil(11);
+    let sieve = Sieve::new(max_house);
+    (1..=max_house).find(|&house| {
+        // Without the visit limit every elf delivers, which is cheap to rule out first.
+        if sieve.sigma(house) * 11 < lower_bound {
+            return false;
+        }
+        let elves = sieve.divisors(house).into_iter();
+        let presents: usize = elves.filter(|elf| house / elf <= 50).sum();
+        presents * 11 >= lower_bound
+    })
+}
+
+/// Returns the first house receiving at least the given number of presents.
+pub fn part_one(input: &str) -> Option<usize> {
+    let lower_bound = input.parse().unwrap();
+    find_house(lower_bound)
+}
+
+/// Returns the first house receiving at least the given number of presents from lazy elves.
+pub fn part_two(input: &str) -> Option<usize> {
+    let lower_bound = input.parse().unwrap();
+    find_house_lazy(lower_bound)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(Some(1), find_house(10));
+        assert_eq!(Some(2), find_house(11));
+        assert_eq!(Some(2), find_house(30));
+        assert_eq!(Some(3), find_house(40));
+        assert_eq!(Some(4), find_house(60));
+        assert_eq!(Some(4), find_house(70));
+        assert_eq!(Some(6), find_house(80));
+        assert_eq!(Some(6), find_house(120));
+        assert_eq!(Some(8), find_house(130));
+        assert_eq!(Some(8), find_house(150));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(Some(1), find_house_lazy(11));
+        assert_eq!(Some(2), find_house_lazy(21));
+        assert_eq!(Some(4), find_house_lazy(70));
+        assert_eq!(Some(6), find_house_lazy(110));
+    }
+
+    #[test]
+    fn test_against_simulation() {
+        // Deliver presents house by house, which only works for small bounds. House
+        // `lower_bound` gets at least that many presents from its own elf, so it is a safe limit.
+        let simulate = |lower_bound: usize, per_elf: usize, visits: usize| {
+            let max_house = lower_bound;
+            let mut houses = vec![0; max_house + 1];
+            for elf in 1..=max_house {
+                for house in (elf..=max_house).step_by(elf).take(visits) {
+                    houses[house] += elf * per_elf;
+                }
+            }
+            (1..=max_house).find(|house| houses[*house] >= lower_bound)
+        };
+        for lower_bound in (1..200).chain((200..20000).step_by(97)) {
+            assert_eq!(
+                find_house(lower_bound),
+                simulate(lower_bound, 10, usize::MAX)
+            );
+            assert_eq!(find_house_lazy(lower_bound), simulate(lower_bound, 11, 50));
+        }
+    }
+}
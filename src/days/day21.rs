@@ -0,0 +1,215 @@
+//! Day 21: RPG Simulator 20XX
+
+use itertools::{iproduct, Itertools};
+
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(21);
+
+/// The hit points the player starts with.
+pub const PLAYER_HITPOINTS: isize = 100;
+
+const SHOP_STR: &str = r#"
+Weapons:    Cost  Damage  Armor
+Dagger        8     4       0
+Shortsword   10     5       0
+Warhammer    25     6       0
+Longsword    40     7       0
+Greataxe     74     8       0
+
+Armor:      Cost  Damage  Armor
+Leather      13     0       1
+Chainmail    31     0       2
+Splintmail   53     0       3
+Bandedmail   75     0       4
+Platemail   102     0       5
+
+Rings:      Cost  Damage  Armor
+Damage+1     25     1       0
+Damage+2     50     2       0
+Damage+3    100     3       0
+Defense+1    20     0       1
+Defense+2    40     0       2
+Defense+3    80     0       3
+"#;
+
+/// The combat statistics of a fighter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Stats {
+    /// Remaining hit points.
+    pub hitpoints: isize,
+    /// Damage dealt per attack, before armor.
+    pub damage: isize,
+    /// Damage absorbed per attack, down to a minimum of 1.
+    pub armor: isize,
+}
+
+impl Stats {
+    /// Creates a fighter wearing `items`.
+    pub fn new(hitpoints: isize, items: &[&Item]) -> Self {
+        let damage = items.iter().map(|i| i.damage).sum();
+        let armor = items.iter().map(|i| i.armor).sum();
+        Self {
+            hitpoints,
+            damage,
+            armor,
+        }
+    }
+
+    /// Returns the damage a single attack of `self` deals to `other`.
+    pub fn hit(&self, other: &Stats) -> isize {
+        (self.damage - other.armor).max(1)
+    }
+
+    /// Checks whether `self` wins when attacking first.
+    pub fn fight(&self, other: &Stats) -> bool {
+        let self_damage = self.hit(other);
+        let other_damage = other.hit(self);
+        let self_turns = other.hitpoints / self_damage + (other.hitpoints % self_damage).min(1);
+        let other_turns = self.hitpoints / other_damage + (self.hitpoints % other_damage).min(1);
+        self_turns <= other_turns
+    }
+}
+
+impl From<&str> for Stats {
+    fn from(value: &str) -> Self {
+        let mut lines = value.lines();
+        let hitpoints = lines
+            .next()
+            .unwrap()
+            .strip_prefix("Hit Points: ")
+            .unwrap()
+            .parse()
+            .unwrap();
+        let damage = lines
+            .next()
+            .unwrap()
+            .strip_prefix("Damage: ")
+            .unwrap()
+            .parse()
+            .unwrap();
+        // Bosses without armor leave out the line.
+        let armor = lines.next().map_or(0, |line| {
+            line.strip_prefix("Armor: ").unwrap().parse().unwrap()
+        });
+        Self {
+            hitpoints,
+            damage,
+            armor,
+        }
+    }
+}
+
+/// An item from the shop.
+#[derive(Debug)]
+pub struct Item {
+    /// The name of the item.
+    pub name: String,
+    /// The price in gold.
+    pub cost: usize,
+    /// The damage bonus.
+    pub damage: isize,
+    /// The armor bonus.
+    pub armor: isize,
+}
+
+impl From<&str> for Item {
+    fn from(value: &str) -> Self {
+        let mut tokens = value.split_whitespace();
+        let name = tokens.next().unwrap().to_string();
+        let cost = tokens.next().unwrap().parse().unwrap();
+        let damage = tokens.next().unwrap().parse().unwrap();
+        let armor = tokens.next().unwrap().parse().unwrap();
+        Self {
+            name,
+            cost,
+            damage,
+            armor,
+        }
+    }
+}
+
+/// The item shop, parsed from its price table.
+#[derive(Debug)]
+pub struct Shop {
+    /// The weapons on offer.
+    pub weapons: Vec<Item>,
+    /// The armor on offer.
+    pub armor: Vec<Item>,
+    /// The rings on offer.
+    pub rings: Vec<Item>,
+}
+
+impl Shop {
+    fn generate(
+        &self,
+        weapons: usize,
+        armor: usize,
+        rings: usize,
+    ) -> impl Iterator<Item = Vec<&Item>> {
+        iproduct!(
+            self.weapons.iter().combinations(weapons),
+            self.armor.iter().combinations(armor),
+            self.rings.iter().combinations(rings)
+        )
+        .map(|(mut w, mut a, mut r)| {
+            w.append(&mut a);
+            w.append(&mut r);
+            w
+        })
+    }
+
+    /// Enumerates every legal loadout: one weapon, at most one armor and up to two rings.
+    pub fn combos(&self) -> impl Iterator<Item = Vec<&Item>> {
+        let weapons = 1..=1; // 1 weapon required
+        let armor = 0..=1; // 1 armor is optional
+        let rings = 0..=2; // 0-2 rings
+        iproduct!(weapons, armor, rings).flat_map(|(w, a, r)| self.generate(w, a, r))
+    }
+}
+
+impl From<&str> for Shop {
+    fn from(value: &str) -> Self {
+        let mut shop_str = value.split("\n\n");
+        let weapons_str = shop_str.next().unwrap();
+        let armor_str = shop_str.next().unwrap();
+        let rings_str = shop_str.next().unwrap();
+
+        let weapons: Vec<_> = weapons_str.lines().skip(1).map(Item::from).collect();
+        let armor: Vec<_> = armor_str.lines().skip(1).map(Item::from).collect();
+        let rings: Vec<_> = rings_str.lines().skip(1).map(Item::from).collect();
+
+        Self {
+            weapons,
+            armor,
+            rings,
+        }
+    }
+}
+
+/// Returns the least gold spent to win against the boss.
+pub fn part_one(input: &str) -> Option<usize> {
+    let boss: Stats = input.into();
+    let shop: Shop = SHOP_STR.trim().into();
+    shop.combos()
+        .filter(|combo| {
+            let player = Stats::new(PLAYER_HITPOINTS, combo);
+            player.fight(&boss)
+        })
+        .map(|combo| combo.iter().map(|i| i.cost).sum())
+        .min()
+}
+
+/// Returns the most gold spent while still losing against the boss.
+pub fn part_two(input: &str) -> Option<usize> {
+    let boss: Stats = input.into();
+    let shop: Shop = SHOP_STR.trim().into();
+    shop.combos()
+        .filter(|combo| {
+            let player = Stats::new(PLAYER_HITPOINTS, combo);
+            !player.fight(&boss)
+        })
+        .map(|combo| combo.iter().map(|i| i.cost).sum())
+        .max()
+}
@@ -0,0 +1,399 @@
+//! Day 22: Wizard Simulator 20XX
+
+use std::fmt::{self, Display};
+
+use crate::days::day21::Stats;
+use crate::graph;
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(22);
+
+/// The hit points the player starts with.
+pub const PLAYER_HITPOINTS: isize = 50;
+
+/// The mana the player starts with.
+pub const PLAYER_MANA: usize = 500;
+
+/// A spell the player can cast instead of attacking.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Spell {
+    /// Deals 4 damage.
+    MagicMissile,
+    /// Deals 2 damage and heals the player by 2 hit points.
+    Drain,
+    /// Increases armor by 7 for 6 turns.
+    Shield,
+    /// Deals 3 damage at the start of each turn for 6 turns.
+    Poison,
+    /// Gives 101 mana at the start of each turn for 5 turns.
+    Recharge,
+}
+
+impl Spell {
+    /// All spells, from cheapest to most expensive.
+    pub const ALL: [Spell; 5] = [
+        Spell::MagicMissile,
+        Spell::Drain,
+        Spell::Shield,
+        Spell::Poison,
+        Spell::Recharge,
+    ];
+
+    /// Returns the mana it costs to cast the spell.
+    pub fn cost(self) -> usize {
+        match self {
+            Spell::MagicMissile => 53,
+            Spell::Drain => 73,
+            Spell::Shield => 113,
+            Spell::Poison => 173,
+            Spell::Recharge => 229,
+        }
+    }
+}
+
+impl Display for Spell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Spell::MagicMissile => "Magic Missile",
+            Spell::Drain => "Drain",
+            Spell::Shield => "Shield",
+            Spell::Poison => "Poison",
+            Spell::Recharge => "Recharge",
+        })
+    }
+}
+
+/// Whether the player loses a hit point at the start of each of their turns.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Difficulty {
+    /// The rules of part one.
+    Normal,
+    /// The rules of part two.
+    Hard,
+}
+
+/// The state of a battle at the moment the player is about to cast a spell.
+///
+/// A round consists of the player's cast, the boss's turn and the start of the player's next
+/// turn, so that every effect that could end the battle has been applied by the time the player
+/// has to decide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Battle {
+    /// The player's hit points and armor. Their damage is unused, as only spells deal damage.
+    pub player: Stats,
+    /// The player's remaining mana.
+    pub mana: usize,
+    /// The boss's hit points and damage.
+    pub boss: Stats,
+    /// The remaining turns of each effect, indexed by [`Battle::SHIELD`], [`Battle::POISON`]
+    /// and [`Battle::RECHARGE`].
+    pub timers: [u8; 3],
+    /// The rules of the battle.
+    pub difficulty: Difficulty,
+}
+
+impl Battle {
+    /// The timer of the Shield effect.
+    pub const SHIELD: usize = 0;
+    /// The timer of the Poison effect.
+    pub const POISON: usize = 1;
+    /// The timer of the Recharge effect.
+    pub const RECHARGE: usize = 2;
+
+    /// Starts a battle, which begins with the player's first turn.
+    pub fn new(hitpoints: isize, mana: usize, boss: Stats, difficulty: Difficulty) -> Self {
+        let player = Stats {
+            hitpoints,
+            damage: 0,
+            armor: 0,
+        };
+        let mut battle = Self {
+            player,
+            mana,
+            boss,
+            timers: [0; 3],
+            difficulty,
+        };
+        battle.begin_turn(&mut |_| {});
+        battle
+    }
+
+    /// Checks whether the boss is dead.
+    pub fn is_won(&self) -> bool {
+        self.boss.hitpoints <= 0
+    }
+
+    /// Checks whether the player is dead.
+    pub fn is_lost(&self) -> bool {
+        !self.is_won() && self.player.hitpoints <= 0
+    }
+
+    /// Checks whether the player can afford `spell` and its effect is not already active.
+    pub fn can_cast(&self, spell: Spell) -> bool {
+        let timer = match spell {
+            Spell::Shield => self.timers[Self::SHIELD],
+            Spell::Poison => self.timers[Self::POISON],
+            Spell::Recharge => self.timers[Self::RECHARGE],
+            Spell::MagicMissile | Spell::Drain => 0,
+        };
+        timer == 0 && spell.cost() <= self.mana
+    }
+
+    /// Plays a round in which the player casts `spell`.
+    /// Returns `None` if the battle is over or the spell cannot be cast.
+    pub fn round(&self, spell: Spell) -> Option<Self> {
+        self.round_logged(spell, &mut |_| {})
+    }
+
+    /// Replays a sequence of spells, returning the final state and a turn-by-turn log.
+    /// Stops early if the battle ends or a spell cannot be cast.
+    pub fn replay(&self, spells: &[Spell]) -> (Self, Vec<String>) {
+        let mut log = vec![];
+        let mut battle = *self;
+        log.push("-- Player turn --".to_string());
+        battle.log_status(&mut |line| log.push(line.to_string()));
+
+        for &spell in spells {
+            let next = battle.round_logged(spell, &mut |line| log.push(line.to_string()));
+            match next {
+                Some(next) => battle = next,
+                None => {
+                    log.push(format!("Player cannot cast {spell}."));
+                    break;
+                }
+            }
+        }
+
+        if battle.is_won() {
+            log.push("This kills the boss, and the player wins.".to_string());
+        } else if battle.is_lost() {
+            log.push("This kills the player, and the boss wins.".to_string());
+        }
+        (battle, log)
+    }
+
+    fn round_logged(&self, spell: Spell, log: &mut impl FnMut(fmt::Arguments)) -> Option<Self> {
+        if self.is_won() || self.is_lost() || !self.can_cast(spell) {
+            return None;
+        }
+
+        let mut next = *self;
+        next.mana -= spell.cost();
+        log(format_args!("Player casts {spell}."));
+        match spell {
+            Spell::MagicMissile => next.boss.hitpoints -= 4,
+            Spell::Drain => {
+                next.boss.hitpoints -= 2;
+                next.player.hitpoints += 2;
+            }
+            Spell::Shield => {
+                next.timers[Self::SHIELD] = 6;
+                next.player.armor = 7;
+            }
+            Spell::Poison => next.timers[Self::POISON] = 6,
+            Spell::Recharge => next.timers[Self::RECHARGE] = 5,
+        }
+        if next.is_won() {
+            return Some(next);
+        }
+
+        log(format_args!(""));
+        log(format_args!("-- Boss turn --"));
+        next.log_status(log);
+        next.apply_effects(log);
+        if next.is_won() {
+            return Some(next);
+        }
+
+        let damage = next.boss.hit(&next.player);
+        next.player.hitpoints -= damage;
+        log(format_args!("Boss attacks for {damage} damage."));
+        if next.is_lost() {
+            return Some(next);
+        }
+
+        log(format_args!(""));
+        log(format_args!("-- Player turn --"));
+        next.log_status(log);
+        next.begin_turn(log);
+        Some(next)
+    }
+
+    fn begin_turn(&mut self, log: &mut impl FnMut(fmt::Arguments)) {
+        if self.difficulty == Difficulty::Hard {
+            self.player.hitpoints -= 1;
+            log(format_args!("Player loses 1 hit point."));
+            if self.is_lost() {
+                return;
+            }
+        }
+        self.apply_effects(log);
+    }
+
+    fn apply_effects(&mut self, log: &mut impl FnMut(fmt::Arguments)) {
+        let [shield, poison, recharge] = &mut self.timers;
+        if *shield > 0 {
+            *shield -= 1;
+            log(format_args!("Shield's timer is now {shield}."));
+            if *shield == 0 {
+                self.player.armor = 0;
+                log(format_args!("Shield wears off, decreasing armor by 7."));
+            }
+        }
+        if *poison > 0 {
+            *poison -= 1;
+            self.boss.hitpoints -= 3;
+            log(format_args!(
+                "Poison deals 3 damage; its timer is now {poison}."
+            ));
+        }
+        if *recharge > 0 {
+            *recharge -= 1;
+            self.mana += 101;
+            log(format_args!(
+                "Recharge provides 101 mana; its timer is now {recharge}."
+            ));
+        }
+    }
+
+    fn log_status(&self, log: &mut impl FnMut(fmt::Arguments)) {
+        log(format_args!(
+            "- Player has {} hit points, {} armor, {} mana",
+            self.player.hitpoints, self.player.armor, self.mana
+        ));
+        log(format_args!(
+            "- Boss has {} hit points",
+            self.boss.hitpoints
+        ));
+    }
+}
+
+/// Finds the spells that win the battle with the least mana spent, and the mana spent.
+pub fn cheapest_win(battle: Battle) -> Option<(Vec<Spell>, usize)> {
+    let successors = |battle: &Battle| {
+        let battle = *battle;
+        Spell::ALL.into_iter().filter_map(move |spell| {
+            let next = battle.round(spell).filter(|next| !next.is_lost())?;
+            Some((next, spell.cost()))
+        })
+    };
+    let (path, mana) = graph::dijkstra(battle, successors, Battle::is_won)?;
+
+    // Different spells always lead to different states, so each step has a single cause.
+    let spells = path
+        .windows(2)
+        .map(|step| {
+            Spell::ALL
+                .into_iter()
+                .find(|&spell| step[0].round(spell) == Some(step[1]))
+                .unwrap()
+        })
+        .collect();
+    Some((spells, mana))
+}
+
+fn solve(input: &str, difficulty: Difficulty) -> Option<usize> {
+    let battle = Battle::new(PLAYER_HITPOINTS, PLAYER_MANA, input.into(), difficulty);
+    cheapest_win(battle).map(|(_, mana)| mana)
+}
+
+/// Returns the least mana needed to win.
+pub fn part_one(input: &str) -> Option<usize> {
+    solve(input, Difficulty::Normal)
+}
+
+/// Returns the least mana needed to win on hard difficulty.
+pub fn part_two(input: &str) -> Option<usize> {
+    solve(input, Difficulty::Hard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn boss(hitpoints: isize) -> Stats {
+        Stats {
+            hitpoints,
+            damage: 8,
+            armor: 0,
+        }
+    }
+
+    #[test]
+    fn test_replay() {
+        let battle = Battle::new(10, 250, boss(13), Difficulty::Normal);
+        let (end, log) = battle.replay(&[Spell::Poison, Spell::MagicMissile]);
+        assert!(end.is_won());
+        assert_eq!(end.player.hitpoints, 2);
+        assert_eq!(end.mana, 24);
+        assert_eq!(log[3], "Player casts Poison.");
+        assert!(log.contains(&"Poison deals 3 damage; its timer is now 3.".to_string()));
+        assert_eq!(
+            log.last().unwrap(),
+            "This kills the boss, and the player wins."
+        );
+
+        let battle = Battle::new(10, 250, boss(14), Difficulty::Normal);
+        let spells = [
+            Spell::Recharge,
+            Spell::Shield,
+            Spell::Drain,
+            Spell::Poison,
+            Spell::MagicMissile,
+        ];
+        let (end, _) = battle.replay(&spells);
+        assert!(end.is_won());
+        assert_eq!(end.player.hitpoints, 1);
+        assert_eq!(end.player.armor, 0);
+        assert_eq!(end.mana, 114);
+    }
+
+    #[test]
+    fn test_illegal_casts() {
+        let battle = Battle::new(10, 250, boss(14), Difficulty::Normal);
+        let shielded = battle.round(Spell::Shield).unwrap();
+        assert!(!shielded.can_cast(Spell::Shield));
+        assert_eq!(shielded.round(Spell::Shield), None);
+        assert!(!shielded.can_cast(Spell::Recharge));
+
+        let (end, log) = battle.replay(&[Spell::Shield, Spell::Shield]);
+        assert_eq!(end, shielded);
+        assert_eq!(log.last().unwrap(), "Player cannot cast Shield.");
+    }
+
+    #[test]
+    fn test_cheapest_win() {
+        let battle = Battle::new(10, 250, boss(13), Difficulty::Normal);
+        let (spells, mana) = cheapest_win(battle).unwrap();
+        assert_eq!(spells, vec![Spell::Poison, Spell::MagicMissile]);
+        assert_eq!(mana, 226);
+
+        let battle = Battle::new(10, 250, boss(14), Difficulty::Normal);
+        let (spells, mana) = cheapest_win(battle).unwrap();
+        assert_eq!(mana, spells.iter().map(|s| s.cost()).sum::<usize>());
+        assert!(battle.replay(&spells).0.is_won());
+        assert_eq!(mana, 641);
+    }
+
+    #[test]
+    fn test_hard_mode() {
+        let battle = Battle::new(10, 250, boss(13), Difficulty::Hard);
+        assert_eq!(battle.player.hitpoints, 9);
+        assert_eq!(cheapest_win(battle), None);
+
+        let battle = Battle::new(1, 250, boss(13), Difficulty::Hard);
+        assert!(battle.is_lost());
+        assert_eq!(battle.round(Spell::MagicMissile), None);
+    }
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one("Hit Points: 58\nDamage: 9\n"), Some(1269));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two("Hit Points: 58\nDamage: 9\n"), Some(1309));
+    }
+}
@@ -0,0 +1,55 @@
+//! Day 23: Opening the Turing Lock
+
+use crate::parse;
+use crate::template::Day;
+use crate::vm::{Instr, Machine, VmError};
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(23);
+
+/// Parses one instruction per line.
+pub fn program(input: &str) -> Result<Vec<Instr>, VmError> {
+    Ok(parse::lines(input)
+        .map(Instr::try_from)
+        .collect::<Result<_, _>>()?)
+}
+
+/// Runs the program with register `a` starting at `a`, returning the final value of `register`.
+pub fn run(input: &str, a: i64, register: char) -> Result<i64, VmError> {
+    let program = program(input)?;
+    let mut machine = Machine::new(&program).with_register('a', a);
+    Ok(machine.run()?[register])
+}
+
+/// Returns the value of register `b` once the program halts.
+pub fn part_one(input: &str) -> Result<i64, VmError> {
+    run(input, 0, 'b')
+}
+
+/// Returns the value of register `b` once the program halts, starting with `a` set to 1.
+pub fn part_two(input: &str) -> Result<i64, VmError> {
+    run(input, 1, 'b')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = run(&crate::template::read_file("examples", DAY), 0, 'a');
+        assert_eq!(result, Ok(2));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = run(&crate::template::read_file("examples", DAY), 1, 'a');
+        assert_eq!(result, Ok(7));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        let err = part_one("inc a\njmp two").unwrap_err();
+        assert!(err.to_string().starts_with("line 2, column 5: offset: "));
+    }
+}
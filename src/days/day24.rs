@@ -0,0 +1,141 @@
+//! Day 24: It Hangs in the Balance
+
+use std::ops::ControlFlow;
+
+use crate::parse;
+use crate::subsets;
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(24);
+
+/// The packages of the passenger compartment, in front of the sleigh.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FirstGroup {
+    /// The weights of the packages.
+    pub weights: Vec<u64>,
+    /// The product of the weights.
+    pub quantum_entanglement: u64,
+}
+
+/// Returns the weights that remain once the items at `indices` have been taken out.
+fn without(weights: &[u64], indices: &[usize]) -> Vec<u64> {
+    let mut indices = indices.iter().peekable();
+    weights
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| indices.next_if_eq(&i).is_none())
+        .map(|(_, &weight)| weight)
+        .collect()
+}
+
+/// Checks whether `weights` can be split into `groups` groups of equal weight.
+pub fn can_balance(weights: &[u64], groups: usize) -> bool {
+    let total: u64 = weights.iter().sum();
+    match groups {
+        0 => return weights.is_empty(),
+        1 => return true,
+        _ if !total.is_multiple_of(groups as u64) => return false,
+        _ => {}
+    }
+
+    // Some group holds the first package, so only the groups containing it need to be tried.
+    let Some((&first, rest)) = weights.split_first() else {
+        return total == 0;
+    };
+    let target = total / groups as u64;
+    let Some(needed) = target.checked_sub(first) else {
+        return false;
+    };
+    subsets::subsets_with_sum(rest, needed, |group| {
+        if can_balance(&without(rest, group), groups - 1) {
+            ControlFlow::Break(())
+        } else {
+            ControlFlow::Continue(())
+        }
+    })
+    .is_some()
+}
+
+/// Splits `weights` into `groups` groups of equal weight, choosing the first group with the
+/// fewest packages and then the lowest quantum entanglement.
+/// Returns `None` if the packages cannot be balanced.
+pub fn balance(weights: &[u64], groups: usize) -> Option<FirstGroup> {
+    let total: u64 = weights.iter().sum();
+    if groups == 0 || !total.is_multiple_of(groups as u64) {
+        return None;
+    }
+    let target = total / groups as u64;
+
+    for len in 1..=weights.len() {
+        let mut candidates = vec![];
+        subsets::subsets_with_sum_of_len(weights, target, len, |group| {
+            let group: Vec<_> = group.to_vec();
+            let entanglement = group.iter().map(|&i| weights[i]).product::<u64>();
+            candidates.push((entanglement, group));
+            ControlFlow::<()>::Continue(())
+        });
+        candidates.sort_unstable();
+
+        // The remaining packages must still split evenly, which is checked rather than assumed.
+        let best = candidates
+            .into_iter()
+            .find(|(_, group)| can_balance(&without(weights, group), groups - 1));
+        if let Some((quantum_entanglement, group)) = best {
+            return Some(FirstGroup {
+                weights: group.iter().map(|&i| weights[i]).collect(),
+                quantum_entanglement,
+            });
+        }
+    }
+    None
+}
+
+fn solve(input: &str, groups: usize) -> Option<u64> {
+    let weights: Vec<u64> = parse::ints(input).unwrap_or_else(|err| panic!("{err}"));
+    balance(&weights, groups).map(|group| group.quantum_entanglement)
+}
+
+/// Returns the quantum entanglement of the ideal first group when splitting into three groups.
+pub fn part_one(input: &str) -> Option<u64> {
+    solve(input, 3)
+}
+
+/// Returns the quantum entanglement of the ideal first group when splitting into four groups.
+pub fn part_two(input: &str) -> Option<u64> {
+    solve(input, 4)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(99));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&crate::template::read_file("examples", DAY));
+        assert_eq!(result, Some(44));
+    }
+
+    #[test]
+    fn test_first_group() {
+        let weights = [1, 2, 3, 4, 5, 7, 8, 9, 10, 11];
+        let group = balance(&weights, 3).unwrap();
+        assert_eq!(group.weights, vec![9, 11]);
+        assert_eq!(balance(&weights, 4).unwrap().weights, vec![4, 11]);
+    }
+
+    #[test]
+    fn test_unbalanceable() {
+        // 10 makes a first group, but 6, 6, 6 and 2 cannot make two more groups of 10.
+        assert!(!can_balance(&[6, 6, 6, 2], 2));
+        assert_eq!(balance(&[10, 6, 6, 6, 2], 3), None);
+        assert!(can_balance(&[6, 4, 6, 4], 2));
+        assert_eq!(balance(&[1, 2, 4], 3), None);
+    }
+}
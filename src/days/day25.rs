@@ -0,0 +1,74 @@
+//! Day 25: Let It Snow
+
+use crate::math;
+use crate::parse;
+use crate::template::Day;
+
+/// The day of this puzzle.
+pub const DAY: Day = crate::day!(25);
+
+/// The code in the top left corner of the grid.
+pub const FIRST_CODE: u64 = 20151125;
+
+/// The factor between consecutive codes.
+pub const MULTIPLIER: u64 = 252533;
+
+/// The modulus of all codes.
+pub const MODULUS: u64 = 33554393;
+
+/// Returns the position of the cell at `row` and `column` (both starting at 1) in the order the
+/// codes are filled in, along each diagonal from the bottom left to the top right.
+pub fn index(row: u64, column: u64) -> u64 {
+    let diagonal = row + column - 1;
+    diagonal * (diagonal - 1) / 2 + column - 1
+}
+
+/// Returns the code at `row` and `column`, without generating the codes before it.
+pub fn code(row: u64, column: u64) -> u64 {
+    let factor = math::modpow(MULTIPLIER, index(row, column).into(), MODULUS);
+    FIRST_CODE * factor % MODULUS
+}
+
+/// Returns the code at the row and column named in the input.
+pub fn part_one(input: &str) -> Option<u64> {
+    let line = parse::lines(input).next()?;
+    match line.ints().unwrap_or_else(|err| panic!("{err}"))[..] {
+        [row, column] => Some(code(row, column)),
+        _ => panic!("{}", line.error("expected a row and a column")),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_index() {
+        assert_eq!(index(1, 1), 0);
+        assert_eq!(index(2, 1), 1);
+        assert_eq!(index(1, 2), 2);
+        assert_eq!(index(4, 2), 11);
+        assert_eq!(index(1, 6), 20);
+    }
+
+    #[test]
+    fn test_code() {
+        let mut expected = FIRST_CODE;
+        for n in 0..100 {
+            let diagonal = (1..).find(|d| d * (d + 1) / 2 > n).unwrap();
+            let column = n - diagonal * (diagonal - 1) / 2 + 1;
+            let row = diagonal + 1 - column;
+            assert_eq!(code(row, column), expected, "row {row}, column {column}");
+            expected = expected * MULTIPLIER % MODULUS;
+        }
+        assert_eq!(code(6, 6), 27995004);
+        assert_eq!(code(1, 6), 33511524);
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = "To continue, please consult the code grid in the manual.  \
+            Enter the code at row 4, column 2.";
+        assert_eq!(part_one(input), Some(32451966));
+    }
+}
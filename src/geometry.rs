@@ -0,0 +1,462 @@
+//! Points, directions and bounding boxes for puzzles set on a 2D or 3D lattice.
+//!
+//! The y-axis points down, like rows in the puzzle input, so `North` is `(0, -1)`.
+
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+/// An error which can be returned when parsing a direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(pub String);
+
+impl std::error::Error for ParseDirectionError {}
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid direction {:?}", self.0)
+    }
+}
+
+/// One of the four orthogonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction4 {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction4 {
+    /// All directions, clockwise from the north.
+    pub const ALL: [Direction4; 4] = [
+        Direction4::North,
+        Direction4::East,
+        Direction4::South,
+        Direction4::West,
+    ];
+
+    /// The unit step in this direction.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction4::North => Point2::new(0, -1),
+            Direction4::East => Point2::new(1, 0),
+            Direction4::South => Point2::new(0, 1),
+            Direction4::West => Point2::new(-1, 0),
+        }
+    }
+
+    /// Turns 90 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    /// Turns 90 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// Accepts arrows (`^>v<`), compass points (`NESW`) and `UDLR`, in either case.
+impl TryFrom<char> for Direction4 {
+    type Error = ParseDirectionError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value.to_ascii_uppercase() {
+            '^' | 'N' | 'U' => Ok(Direction4::North),
+            '>' | 'E' | 'R' => Ok(Direction4::East),
+            'V' | 'S' | 'D' => Ok(Direction4::South),
+            '<' | 'W' | 'L' => Ok(Direction4::West),
+            _ => Err(ParseDirectionError(value.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction4 {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(ch), None) => ch.try_into().map_err(|_| ParseDirectionError(s.into())),
+            _ => Err(ParseDirectionError(s.into())),
+        }
+    }
+}
+
+/// One of the four orthogonal or four diagonal directions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction8 {
+    North,
+    NorthEast,
+    East,
+    SouthEast,
+    South,
+    SouthWest,
+    West,
+    NorthWest,
+}
+
+impl Direction8 {
+    /// All directions, clockwise from the north.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::North,
+        Direction8::NorthEast,
+        Direction8::East,
+        Direction8::SouthEast,
+        Direction8::South,
+        Direction8::SouthWest,
+        Direction8::West,
+        Direction8::NorthWest,
+    ];
+
+    /// The unit step in this direction.
+    pub fn offset(self) -> Point2 {
+        match self {
+            Direction8::North => Point2::new(0, -1),
+            Direction8::NorthEast => Point2::new(1, -1),
+            Direction8::East => Point2::new(1, 0),
+            Direction8::SouthEast => Point2::new(1, 1),
+            Direction8::South => Point2::new(0, 1),
+            Direction8::SouthWest => Point2::new(-1, 1),
+            Direction8::West => Point2::new(-1, 0),
+            Direction8::NorthWest => Point2::new(-1, -1),
+        }
+    }
+
+    /// Turns 45 degrees clockwise.
+    #[must_use]
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns 45 degrees counterclockwise.
+    #[must_use]
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    #[must_use]
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Direction4> for Direction8 {
+    fn from(value: Direction4) -> Self {
+        Self::ALL[value as usize * 2]
+    }
+}
+
+/// Accepts the single directions of [`Direction4`] as well as `NE`, `SE`, `SW` and `NW`,
+/// also in the `UR`/`DR`/`DL`/`UL` form.
+impl FromStr for Direction8 {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "NE" | "UR" => Ok(Direction8::NorthEast),
+            "SE" | "DR" => Ok(Direction8::SouthEast),
+            "SW" | "DL" => Ok(Direction8::SouthWest),
+            "NW" | "UL" => Ok(Direction8::NorthWest),
+            _ => s.parse::<Direction4>().map(Direction8::from),
+        }
+    }
+}
+
+/// A point on the 2D integer lattice.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Self { x, y }
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Rotates 90 degrees clockwise around the origin.
+    #[must_use]
+    pub fn rotate_right(self) -> Self {
+        Self::new(-self.y, self.x)
+    }
+
+    /// Rotates 90 degrees counterclockwise around the origin.
+    #[must_use]
+    pub fn rotate_left(self) -> Self {
+        Self::new(self.y, -self.x)
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Direction4::ALL.into_iter().map(move |dir| self + dir)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        Direction8::ALL.into_iter().map(move |dir| self + dir)
+    }
+}
+
+impl From<(isize, isize)> for Point2 {
+    fn from((x, y): (isize, isize)) -> Self {
+        Self::new(x, y)
+    }
+}
+
+impl Display for Point2 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// A point on the 3D integer lattice.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: isize,
+    pub y: isize,
+    pub z: isize,
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const fn new(x: isize, y: isize, z: isize) -> Self {
+        Self { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// Iterates over the six points sharing a face with this one.
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        [
+            Point3::new(1, 0, 0),
+            Point3::new(-1, 0, 0),
+            Point3::new(0, 1, 0),
+            Point3::new(0, -1, 0),
+            Point3::new(0, 0, 1),
+            Point3::new(0, 0, -1),
+        ]
+        .into_iter()
+        .map(move |offset| self + offset)
+    }
+}
+
+impl From<(isize, isize, isize)> for Point3 {
+    fn from((x, y, z): (isize, isize, isize)) -> Self {
+        Self::new(x, y, z)
+    }
+}
+
+impl Display for Point3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! impl_point_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = Self;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field + rhs.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = Self;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self { $($field: self.$field - rhs.$field),+ }
+            }
+        }
+
+        impl Mul<isize> for $point {
+            type Output = Self;
+
+            fn mul(self, rhs: isize) -> Self::Output {
+                Self { $($field: self.$field * rhs),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = Self;
+
+            fn neg(self) -> Self::Output {
+                Self { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, rhs: Self) {
+                *self = *self + rhs;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, rhs: Self) {
+                *self = *self - rhs;
+            }
+        }
+    };
+}
+
+impl_point_ops!(Point2 { x, y });
+impl_point_ops!(Point3 { x, y, z });
+
+impl Add<Direction4> for Point2 {
+    type Output = Self;
+
+    fn add(self, rhs: Direction4) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl AddAssign<Direction4> for Point2 {
+    fn add_assign(&mut self, rhs: Direction4) {
+        *self = *self + rhs;
+    }
+}
+
+impl Add<Direction8> for Point2 {
+    type Output = Self;
+
+    fn add(self, rhs: Direction8) -> Self::Output {
+        self + rhs.offset()
+    }
+}
+
+impl AddAssign<Direction8> for Point2 {
+    fn add_assign(&mut self, rhs: Direction8) {
+        *self = *self + rhs;
+    }
+}
+
+/// The smallest axis-aligned rectangle containing a set of points, bounds inclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct BoundingBox {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl BoundingBox {
+    /// Returns the bounding box of the points, or `None` if there are none.
+    pub fn from_points(points: impl IntoIterator<Item = Point2>) -> Option<Self> {
+        let mut points = points.into_iter();
+        let first = points.next()?;
+        let mut bounds = Self {
+            min: first,
+            max: first,
+        };
+        points.for_each(|point| bounds.include(point));
+        Some(bounds)
+    }
+
+    /// Grows the box to contain `point`.
+    pub fn include(&mut self, point: Point2) {
+        self.min = Point2::new(self.min.x.min(point.x), self.min.y.min(point.y));
+        self.max = Point2::new(self.max.x.max(point.x), self.max.y.max(point.y));
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+
+    pub fn width(&self) -> usize {
+        self.min.x.abs_diff(self.max.x) + 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.min.y.abs_diff(self.max.y) + 1
+    }
+
+    /// Iterates over all points in the box, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point2> {
+        let Self { min, max } = *self;
+        (min.y..=max.y).flat_map(move |y| (min.x..=max.x).map(move |x| Point2::new(x, y)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_directions() {
+        for chars in ["^>v<", "NESW", "UDLR"] {
+            let dirs: Vec<Direction4> = chars.chars().map(|c| c.try_into().unwrap()).collect();
+            let expected = if chars == "UDLR" {
+                vec![
+                    Direction4::North,
+                    Direction4::South,
+                    Direction4::West,
+                    Direction4::East,
+                ]
+            } else {
+                Direction4::ALL.to_vec()
+            };
+            assert_eq!(dirs, expected);
+        }
+        assert_eq!("s".parse(), Ok(Direction4::South));
+        assert!("x".parse::<Direction4>().is_err());
+        assert!("NN".parse::<Direction4>().is_err());
+        assert_eq!("NW".parse(), Ok(Direction8::NorthWest));
+        assert_eq!("E".parse(), Ok(Direction8::East));
+    }
+
+    #[test]
+    fn turns() {
+        assert_eq!(Direction4::North.turn_right(), Direction4::East);
+        assert_eq!(Direction4::North.turn_left(), Direction4::West);
+        assert_eq!(Direction4::East.reverse(), Direction4::West);
+        assert_eq!(Direction8::North.turn_left(), Direction8::NorthWest);
+        assert_eq!(Direction8::SouthEast.reverse(), Direction8::NorthWest);
+        for dir in Direction4::ALL {
+            assert_eq!(dir.offset().rotate_right(), dir.turn_right().offset());
+            assert_eq!(dir.offset().rotate_left(), dir.turn_left().offset());
+            assert_eq!(Direction8::from(dir).offset(), dir.offset());
+        }
+    }
+
+    #[test]
+    fn point_arithmetic() {
+        let p = Point2::new(3, -4);
+        assert_eq!(p.manhattan(Point2::ORIGIN), 7);
+        assert_eq!(p + Direction4::North, Point2::new(3, -5));
+        assert_eq!(p - p * 2, -p);
+        assert_eq!(p.neighbours8().count(), 8);
+
+        let q = Point3::new(1, -2, 3);
+        assert_eq!(q.manhattan(Point3::ORIGIN), 6);
+        assert_eq!(q + q, q * 2);
+        assert!(q.neighbours6().all(|n| n.manhattan(q) == 1));
+    }
+
+    #[test]
+    fn bounding_boxes() {
+        let points = [Point2::new(1, 5), Point2::new(-2, 3), Point2::new(4, 4)];
+        let bounds = BoundingBox::from_points(points).unwrap();
+        assert_eq!(bounds.min, Point2::new(-2, 3));
+        assert_eq!(bounds.max, Point2::new(4, 5));
+        assert_eq!((bounds.width(), bounds.height()), (7, 3));
+        assert!(bounds.contains(Point2::new(0, 4)));
+        assert!(!bounds.contains(Point2::new(0, 6)));
+        assert_eq!(bounds.points().count(), 21);
+        assert_eq!(BoundingBox::from_points([]), None);
+    }
+}
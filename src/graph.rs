@@ -0,0 +1,518 @@
+//! Graph construction and search algorithms over generic node types.
+//!
+//! The search functions take the start node(s) and a `successors` closure, so they work equally
+//! well on an explicit [`Graph`] and on implicit state spaces which are never materialized.
+//! Each node is cloned into the internal bookkeeping once, and paths are only built on success.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A directed graph stored as an adjacency map from each node to its weighted edges.
+#[derive(Clone, Debug)]
+pub struct Graph<N, W = usize> {
+    edges: HashMap<N, Vec<(N, W)>>,
+}
+
+impl<N, W> Default for Graph<N, W> {
+    fn default() -> Self {
+        Self {
+            edges: HashMap::new(),
+        }
+    }
+}
+
+impl<N: Clone + Eq + Hash, W: Clone> Graph<N, W> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a node without any edges. Adding an existing node does nothing.
+    pub fn add_node(&mut self, node: N) {
+        self.edges.entry(node).or_default();
+    }
+
+    /// Adds a directed edge, adding both nodes if necessary.
+    pub fn add_edge(&mut self, from: N, to: N, weight: W) {
+        self.add_node(to.clone());
+        self.edges.entry(from).or_default().push((to, weight));
+    }
+
+    /// Adds an edge in both directions.
+    pub fn add_undirected_edge(&mut self, a: N, b: N, weight: W) {
+        self.add_edge(a.clone(), b.clone(), weight.clone());
+        self.add_edge(b, a, weight);
+    }
+
+    pub fn len(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.edges.is_empty()
+    }
+
+    pub fn contains(&self, node: &N) -> bool {
+        self.edges.contains_key(node)
+    }
+
+    /// Iterates over all nodes, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = &N> {
+        self.edges.keys()
+    }
+
+    /// Iterates over the outgoing edges of `node` and their weights.
+    pub fn edges(&self, node: &N) -> impl Iterator<Item = (&N, &W)> {
+        self.edges
+            .get(node)
+            .into_iter()
+            .flatten()
+            .map(|(to, weight)| (to, weight))
+    }
+
+    /// Iterates over the nodes reachable from `node` through a single edge.
+    pub fn successors(&self, node: &N) -> impl Iterator<Item = N> + '_ {
+        self.edges
+            .get(node)
+            .into_iter()
+            .flatten()
+            .map(|(to, _)| to.clone())
+    }
+
+    /// Looks up the weight of the edge between two nodes.
+    pub fn weight(&self, from: &N, to: &N) -> Option<&W> {
+        self.edges(from).find(|(n, _)| *n == to).map(|(_, w)| w)
+    }
+
+    /// Orders all nodes so that every edge points forward, see [`toposort`].
+    pub fn toposort(&self) -> Result<Vec<N>, Cycle<N>> {
+        toposort(self.nodes().cloned(), |node| self.successors(node))
+    }
+
+    /// Groups the nodes which are connected when ignoring edge directions,
+    /// see [`connected_components`].
+    pub fn connected_components(&self) -> Vec<Vec<N>> {
+        connected_components(self.nodes().cloned(), |node| self.successors(node))
+    }
+}
+
+impl<N, W> Graph<N, W>
+where
+    N: Clone + Eq + Hash,
+    W: Copy + Ord + Default + Add<Output = W>,
+{
+    /// Finds the cheapest path between two nodes, see [`dijkstra`].
+    pub fn shortest_path(&self, from: &N, to: &N) -> Option<(Vec<N>, W)> {
+        dijkstra(
+            from.clone(),
+            |node| self.edges(node).map(|(n, w)| (n.clone(), *w)),
+            |node| node == to,
+        )
+    }
+}
+
+impl<N: Clone + Eq + Hash, W: Clone> FromIterator<(N, N, W)> for Graph<N, W> {
+    fn from_iter<T: IntoIterator<Item = (N, N, W)>>(iter: T) -> Self {
+        let mut graph = Self::new();
+        for (from, to, weight) in iter {
+            graph.add_edge(from, to, weight);
+        }
+        graph
+    }
+}
+
+/// The error returned by [`toposort`] when the graph is not acyclic.
+/// Contains the nodes of one cycle, in edge order.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cycle<N>(pub Vec<N>);
+
+impl<N: Debug> std::error::Error for Cycle<N> {}
+
+impl<N: Debug> Display for Cycle<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "graph contains a cycle: ")?;
+        for node in &self.0 {
+            write!(f, "{node:?} -> ")?;
+        }
+        match self.0.first() {
+            Some(first) => write!(f, "{first:?}"),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Walks `parents` back from `end` to a node without parent, returning the path in order.
+fn build_path<N: Clone>(nodes: &[(N, usize)], end: usize) -> Vec<N> {
+    let mut path = vec![];
+    let mut current = end;
+    loop {
+        let (node, parent) = &nodes[current];
+        path.push(node.clone());
+        if *parent == current {
+            break;
+        }
+        current = *parent;
+    }
+    path.reverse();
+    path
+}
+
+/// Breadth-first search from `start` to the first node satisfying `is_goal`.
+/// Returns the path with the fewest edges, including both ends.
+pub fn bfs<N, FS, IS, FG>(start: N, mut successors: FS, mut is_goal: FG) -> Option<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = N>,
+    FG: FnMut(&N) -> bool,
+{
+    let mut nodes = vec![(start.clone(), 0)];
+    let mut seen = HashSet::from([start]);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        if is_goal(&nodes[index].0) {
+            return Some(build_path(&nodes, index));
+        }
+        for next in successors(&nodes[index].0) {
+            if seen.insert(next.clone()) {
+                queue.push_back(nodes.len());
+                nodes.push((next, index));
+            }
+        }
+    }
+    None
+}
+
+/// Breadth-first search of every node reachable from `start`, returning the number of edges
+/// needed to reach each one.
+pub fn bfs_reach<N, FS, IS>(start: N, mut successors: FS) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in successors(&node) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    distances
+}
+
+/// Depth-first search of every node reachable from `start`, returning them in preorder.
+pub fn dfs<N, FS, IS>(start: N, mut successors: FS) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = N>,
+{
+    let mut order = vec![];
+    let mut seen = HashSet::new();
+    let mut stack = vec![start];
+
+    while let Some(node) = stack.pop() {
+        if !seen.insert(node.clone()) {
+            continue;
+        }
+        let first = stack.len();
+        stack.extend(successors(&node).into_iter().filter(|n| !seen.contains(n)));
+        // Visit successors in the order they were given.
+        stack[first..].reverse();
+        order.push(node);
+    }
+    order
+}
+
+/// Finds the cheapest path from `start` to a node satisfying `is_goal`, where `successors`
+/// yields each neighbour along with the non-negative cost of moving there.
+/// Returns the path, including both ends, and its total cost.
+pub fn dijkstra<N, C, FS, IS, FG>(start: N, successors: FS, is_goal: FG) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = (N, C)>,
+    FG: FnMut(&N) -> bool,
+{
+    astar(start, successors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but guided by a `heuristic` which estimates the remaining cost to a goal.
+/// The result is only guaranteed to be optimal if the heuristic never overestimates.
+pub fn astar<N, C, FS, IS, FH, FG>(
+    start: N,
+    mut successors: FS,
+    mut heuristic: FH,
+    mut is_goal: FG,
+) -> Option<(Vec<N>, C)>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Default + Add<Output = C>,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = (N, C)>,
+    FH: FnMut(&N) -> C,
+    FG: FnMut(&N) -> bool,
+{
+    // Every node gets an index into `nodes`, which holds its parent and best known cost.
+    let mut indices = HashMap::from([(start.clone(), 0)]);
+    let mut nodes = vec![(start.clone(), 0)];
+    let mut costs = vec![C::default()];
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        if cost > costs[index] {
+            continue;
+        }
+        if is_goal(&nodes[index].0) {
+            return Some((build_path(&nodes, index), cost));
+        }
+        for (next, step) in successors(&nodes[index].0) {
+            let next_cost = cost + step;
+            let next_index = match indices.entry(next) {
+                Entry::Vacant(entry) => {
+                    let next_index = nodes.len();
+                    nodes.push((entry.key().clone(), index));
+                    costs.push(next_cost);
+                    entry.insert(next_index);
+                    next_index
+                }
+                Entry::Occupied(entry) => {
+                    let next_index = *entry.get();
+                    if next_cost >= costs[next_index] {
+                        continue;
+                    }
+                    nodes[next_index].1 = index;
+                    costs[next_index] = next_cost;
+                    next_index
+                }
+            };
+            let estimate = next_cost + heuristic(&nodes[next_index].0);
+            heap.push(Reverse((estimate, next_cost, next_index)));
+        }
+    }
+    None
+}
+
+/// Orders the nodes reachable from `roots` such that every node comes before its successors,
+/// or returns a [`Cycle`] if there is no such order.
+pub fn toposort<N, FS, IS>(
+    roots: impl IntoIterator<Item = N>,
+    mut successors: FS,
+) -> Result<Vec<N>, Cycle<N>>
+where
+    N: Clone + Eq + Hash,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = N>,
+{
+    // Nodes on the current path map to `false`, finished nodes to `true`.
+    let mut finished: HashMap<N, bool> = HashMap::new();
+    let mut order = vec![];
+
+    for root in roots {
+        if finished.contains_key(&root) {
+            continue;
+        }
+        finished.insert(root.clone(), false);
+        let successors_of_root = successors(&root).into_iter();
+        let mut stack = vec![(root, successors_of_root)];
+
+        while let Some((node, pending)) = stack.last_mut() {
+            match pending.next() {
+                Some(next) => match finished.get(&next) {
+                    Some(true) => {}
+                    Some(false) => {
+                        let start = stack.iter().position(|(n, _)| *n == next).unwrap();
+                        return Err(Cycle(stack.drain(start..).map(|(n, _)| n).collect()));
+                    }
+                    None => {
+                        finished.insert(next.clone(), false);
+                        let pending = successors(&next).into_iter();
+                        stack.push((next, pending));
+                    }
+                },
+                None => {
+                    finished.insert(node.clone(), true);
+                    let (node, _) = stack.pop().unwrap();
+                    order.push(node);
+                }
+            }
+        }
+    }
+
+    order.reverse();
+    Ok(order)
+}
+
+/// Groups `nodes`, and everything reachable from them, into sets which are connected when
+/// ignoring edge directions.
+pub fn connected_components<N, FS, IS>(
+    nodes: impl IntoIterator<Item = N>,
+    mut successors: FS,
+) -> Vec<Vec<N>>
+where
+    N: Clone + Eq + Hash,
+    FS: FnMut(&N) -> IS,
+    IS: IntoIterator<Item = N>,
+{
+    // Union-find over node indices, merging the two ends of every edge.
+    let mut indices: HashMap<N, usize> = HashMap::new();
+    let mut all = vec![];
+    let mut parents: Vec<usize> = vec![];
+
+    fn find(parents: &mut [usize], mut i: usize) -> usize {
+        while parents[i] != i {
+            parents[i] = parents[parents[i]];
+            i = parents[i];
+        }
+        i
+    }
+
+    let mut index_of = |node: N, all: &mut Vec<N>, parents: &mut Vec<usize>| {
+        *indices.entry(node).or_insert_with_key(|node| {
+            all.push(node.clone());
+            parents.push(parents.len());
+            parents.len() - 1
+        })
+    };
+
+    let mut pending: Vec<usize> = nodes
+        .into_iter()
+        .map(|node| index_of(node, &mut all, &mut parents))
+        .collect();
+    let mut visited = vec![false; all.len()];
+
+    while let Some(index) = pending.pop() {
+        if visited.get(index).copied().unwrap_or(false) {
+            continue;
+        }
+        visited.resize(all.len().max(index + 1), false);
+        visited[index] = true;
+
+        for next in successors(&all[index]) {
+            let next = index_of(next, &mut all, &mut parents);
+            let (a, b) = (find(&mut parents, index), find(&mut parents, next));
+            parents[a] = b;
+            pending.push(next);
+        }
+    }
+
+    let mut groups: HashMap<usize, Vec<N>> = HashMap::new();
+    for (i, node) in all.into_iter().enumerate() {
+        groups.entry(find(&mut parents, i)).or_default().push(node);
+    }
+    groups.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> Graph<char> {
+        [
+            ('a', 'b', 7),
+            ('a', 'c', 9),
+            ('a', 'f', 14),
+            ('b', 'c', 10),
+            ('b', 'd', 15),
+            ('c', 'd', 11),
+            ('c', 'f', 2),
+            ('d', 'e', 6),
+            ('f', 'e', 9),
+        ]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn builds_adjacency() {
+        let graph = example();
+        assert_eq!(graph.len(), 6);
+        assert!(graph.contains(&'e'));
+        assert_eq!(graph.successors(&'e').count(), 0);
+        assert_eq!(graph.weight(&'c', &'f'), Some(&2));
+        assert_eq!(graph.weight(&'f', &'c'), None);
+    }
+
+    #[test]
+    fn breadth_and_depth_first() {
+        let graph = example();
+        let path = bfs('a', |n| graph.successors(n), |n| *n == 'e').unwrap();
+        assert_eq!(path, vec!['a', 'f', 'e']);
+        assert_eq!(bfs('e', |n| graph.successors(n), |n| *n == 'a'), None);
+
+        let reach = bfs_reach('b', |n| graph.successors(n));
+        assert_eq!(reach.len(), 5);
+        assert_eq!(reach[&'e'], 2);
+
+        let order = dfs('a', |n| graph.successors(n));
+        assert_eq!(order, vec!['a', 'b', 'c', 'd', 'e', 'f']);
+    }
+
+    #[test]
+    fn cheapest_paths() {
+        let graph = example();
+        assert_eq!(
+            graph.shortest_path(&'a', &'e'),
+            Some((vec!['a', 'c', 'f', 'e'], 20))
+        );
+
+        // Manhattan distance on an open grid, with a wall at x = 2 except at y = 5.
+        let goal = (4i32, 0i32);
+        let successors = |&(x, y): &(i32, i32)| {
+            [(x + 1, y), (x - 1, y), (x, y + 1), (x, y - 1)]
+                .into_iter()
+                .filter(|&(x, y)| (0..=5).contains(&x) && (0..=5).contains(&y))
+                .filter(|&(x, y)| x != 2 || y == 5)
+                .map(|p| (p, 1))
+        };
+        let heuristic = |&(x, y): &(i32, i32)| x.abs_diff(goal.0) + y.abs_diff(goal.1);
+        let (path, cost) = astar((0, 0), successors, heuristic, |p| *p == goal).unwrap();
+        assert_eq!(cost, 14);
+        assert_eq!(path.len(), 15);
+        let (_, cost) = dijkstra((0, 0), successors, |p| *p == goal).unwrap();
+        assert_eq!(cost, 14);
+    }
+
+    #[test]
+    fn topological_order() {
+        let graph = example();
+        let order = graph.toposort().unwrap();
+        let position = |n: char| order.iter().position(|m| *m == n).unwrap();
+        for node in graph.nodes() {
+            for next in graph.successors(node) {
+                assert!(position(*node) < position(next));
+            }
+        }
+
+        let mut cyclic = example();
+        cyclic.add_edge('e', 'c', 1);
+        let Cycle(cycle) = cyclic.toposort().unwrap_err();
+        assert_eq!(cycle.len(), 3);
+        for (i, node) in cycle.iter().enumerate() {
+            assert!(cyclic.weight(node, &cycle[(i + 1) % 3]).is_some());
+        }
+    }
+
+    #[test]
+    fn components() {
+        let mut graph: Graph<u32, ()> = Graph::new();
+        graph.add_edge(1, 2, ());
+        graph.add_edge(3, 2, ());
+        graph.add_undirected_edge(4, 5, ());
+        graph.add_node(6);
+        let mut components = graph.connected_components();
+        components.iter_mut().for_each(|c| c.sort());
+        components.sort();
+        assert_eq!(components, vec![vec![1, 2, 3], vec![4, 5], vec![6]]);
+    }
+}
@@ -0,0 +1,371 @@
+//! A two-dimensional grid of typed cells, as used by many puzzles.
+//!
+//! Cells are addressed by `(x, y)` coordinates, where `x` is the column and `y` the row,
+//! starting at the top left corner.
+
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut, Range, RangeInclusive};
+use std::str::FromStr;
+
+use ndarray::{s, Array2, ArrayView2, ArrayViewMut2};
+
+/// Offsets of the orthogonal neighbours of a cell: north, east, south and west.
+pub const NEIGHBOURS_4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbours of a cell, clockwise from the north.
+pub const NEIGHBOURS_8: [(isize, isize); 8] = [
+    (0, -1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+    (0, 1),
+    (-1, 1),
+    (-1, 0),
+    (-1, -1),
+];
+
+/// An error which can be returned when parsing a [`Grid`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum GridError {
+    /// The input contains no cells.
+    Empty,
+    /// A line has a different width than the first line.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// A character could not be converted into a cell.
+    InvalidCell {
+        line: usize,
+        column: usize,
+        ch: char,
+    },
+}
+
+impl std::error::Error for GridError {}
+
+impl Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GridError::Empty => write!(f, "grid is empty"),
+            GridError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "line {line} has {found} cells, expected {expected} like the first line"
+            ),
+            GridError::InvalidCell { line, column, ch } => {
+                write!(f, "invalid cell {ch:?} at line {line}, column {column}")
+            }
+        }
+    }
+}
+
+/// A rectangular grid of cells of type `T`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+    wrap: bool,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid of `width` by `height` cells, all set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cells: Array2::from_elem((height, width), fill),
+            wrap: false,
+        }
+    }
+
+    /// Parses a grid from lines of text, converting every character with `f`.
+    /// Trailing blank lines are ignored.
+    /// Returns a [`GridError`] if the lines are not of equal width or `f` rejects a character.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> Option<T>) -> Result<Self, GridError> {
+        let mut cells = vec![];
+        let mut width = None;
+        let mut height = 0;
+
+        for (y, line) in input.trim_end_matches(['\r', '\n']).lines().enumerate() {
+            let line = line.trim_end_matches('\r');
+            let found = line.chars().count();
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(GridError::Ragged {
+                    line: y + 1,
+                    expected,
+                    found,
+                });
+            }
+
+            for (x, ch) in line.chars().enumerate() {
+                let cell = f(ch).ok_or(GridError::InvalidCell {
+                    line: y + 1,
+                    column: x + 1,
+                    ch,
+                })?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+
+        let width = width.filter(|w| *w > 0).ok_or(GridError::Empty)?;
+        let cells = Array2::from_shape_vec((height, width), cells).map_err(|_| GridError::Empty)?;
+        Ok(Self { cells, wrap: false })
+    }
+
+    /// Makes coordinates outside of the grid wrap around to the opposite edge, like on a torus.
+    #[must_use]
+    pub fn with_wrap(mut self, wrap: bool) -> Self {
+        self.wrap = wrap;
+        self
+    }
+
+    pub fn width(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    pub fn height(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    /// Returns the position of the cell at `(x, y)`, applying wraparound if enabled,
+    /// or `None` if it is out of bounds or the grid is empty.
+    pub fn position(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        let (width, height) = (self.width() as isize, self.height() as isize);
+        if self.wrap && width > 0 && height > 0 {
+            return Some((x.rem_euclid(width) as usize, y.rem_euclid(height) as usize));
+        }
+        if (0..width).contains(&x) && (0..height).contains(&y) {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    pub fn contains(&self, x: isize, y: isize) -> bool {
+        self.position(x, y).is_some()
+    }
+
+    pub fn get(&self, x: isize, y: isize) -> Option<&T> {
+        let (x, y) = self.position(x, y)?;
+        self.cells.get((y, x))
+    }
+
+    pub fn get_mut(&mut self, x: isize, y: isize) -> Option<&mut T> {
+        let (x, y) = self.position(x, y)?;
+        self.cells.get_mut((y, x))
+    }
+
+    /// Iterates over the positions of the neighbours of `(x, y)` at the given offsets.
+    /// Neighbours outside of the grid are skipped, unless wraparound is enabled.
+    pub fn neighbours<'a>(
+        &'a self,
+        x: usize,
+        y: usize,
+        offsets: &'a [(isize, isize)],
+    ) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        offsets.iter().filter_map(move |(dx, dy)| {
+            let pos = self.position(x as isize + dx, y as isize + dy)?;
+            Some((pos, &self[pos]))
+        })
+    }
+
+    /// Iterates over the orthogonal neighbours of `(x, y)`.
+    pub fn neighbours4(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbours(x, y, &NEIGHBOURS_4)
+    }
+
+    /// Iterates over the orthogonal and diagonal neighbours of `(x, y)`.
+    pub fn neighbours8(&self, x: usize, y: usize) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.neighbours(x, y, &NEIGHBOURS_8)
+    }
+
+    /// Iterates over all cells, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// Iterates over all cells and their `(x, y)` positions, row by row.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells
+            .indexed_iter()
+            .map(|((y, x), cell)| ((x, y), cell))
+    }
+
+    /// Iterates mutably over all cells and their `(x, y)` positions, row by row.
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.cells
+            .indexed_iter_mut()
+            .map(|((y, x), cell)| ((x, y), cell))
+    }
+
+    /// Returns a view of the rectangular region spanned by the column and row ranges.
+    /// The view is indexed by `[y, x]` relative to the top left corner of the region.
+    /// The parts of the region outside of the grid are cut off, which may leave it empty.
+    pub fn region(
+        &self,
+        xs: RangeInclusive<usize>,
+        ys: RangeInclusive<usize>,
+    ) -> ArrayView2<'_, T> {
+        let (xs, ys) = (clamp(xs, self.width()), clamp(ys, self.height()));
+        self.cells.slice(s![ys, xs])
+    }
+
+    /// Returns a mutable view of the rectangular region spanned by the column and row ranges,
+    /// cut off like [`Grid::region`].
+    pub fn region_mut(
+        &mut self,
+        xs: RangeInclusive<usize>,
+        ys: RangeInclusive<usize>,
+    ) -> ArrayViewMut2<'_, T> {
+        let (xs, ys) = (clamp(xs, self.width()), clamp(ys, self.height()));
+        self.cells.slice_mut(s![ys, xs])
+    }
+
+    /// Renders the grid with one character per cell.
+    pub fn render(&self, mut f: impl FnMut(&T) -> char) -> String {
+        self.cells
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().map(&mut f).collect::<String>())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Restricts an inclusive range to the indices below `len`.
+fn clamp(range: RangeInclusive<usize>, len: usize) -> Range<usize> {
+    let end = range.end().saturating_add(1).min(len);
+    (*range.start()).min(end)..end
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.cells[(y, x)]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.cells[(y, x)]
+    }
+}
+
+impl<T: TryFrom<char>> FromStr for Grid<T> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s, |ch| T::try_from(ch).ok())
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.cells.rows().into_iter().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const EXAMPLE: &str = "abc\ndef\nghi\n";
+
+    #[test]
+    fn parses_typed_cells() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 3));
+        assert_eq!(grid[(2, 0)], 'c');
+        assert_eq!(grid.get(0, 2), Some(&'g'));
+        assert_eq!(grid.get(3, 0), None);
+        assert_eq!(grid.get(-1, 0), None);
+
+        let trailing: Grid<char> = "abc\n\n\r\n".parse().unwrap();
+        assert_eq!((trailing.width(), trailing.height()), (3, 1));
+
+        let digits: Grid<u32> = Grid::parse("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.iter().sum::<u32>(), 10);
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        assert_eq!(
+            "ab\nc".parse::<Grid<char>>(),
+            Err(GridError::Ragged {
+                line: 2,
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(
+            Grid::parse("12\n3x", |c| c.to_digit(10)),
+            Err(GridError::InvalidCell {
+                line: 2,
+                column: 2,
+                ch: 'x'
+            })
+        );
+        assert_eq!("".parse::<Grid<char>>(), Err(GridError::Empty));
+        assert_eq!("\n\n".parse::<Grid<char>>(), Err(GridError::Empty));
+    }
+
+    #[test]
+    fn iterates_neighbours() {
+        let grid: Grid<char> = EXAMPLE.parse().unwrap();
+        let corner: String = grid.neighbours8(0, 0).map(|(_, c)| *c).collect();
+        assert_eq!(corner, "bed");
+        let center: String = grid.neighbours4(1, 1).map(|(_, c)| *c).collect();
+        assert_eq!(center, "bfhd");
+    }
+
+    #[test]
+    fn wraps_around() {
+        let grid: Grid<char> = EXAMPLE.parse::<Grid<char>>().unwrap().with_wrap(true);
+        assert_eq!(grid.get(-1, -1), Some(&'i'));
+        assert_eq!(grid.get(3, 4), Some(&'d'));
+        assert_eq!(grid.neighbours8(0, 0).count(), 8);
+
+        let empty = Grid::new(0, 3, '.').with_wrap(true);
+        assert_eq!(empty.get(1, 1), None);
+        assert_eq!(Grid::new(3, 0, '.').with_wrap(true).position(-1, 0), None);
+    }
+
+    #[test]
+    fn slices_regions() {
+        let mut grid = Grid::new(4, 3, 0);
+        grid.region_mut(1..=2, 0..=1).fill(1);
+        assert_eq!(grid.region(0..=3, 1..=1).sum(), 2);
+        assert_eq!(
+            grid.render(|c| if *c > 0 { '#' } else { '.' }),
+            ".##.\n.##.\n...."
+        );
+        assert_eq!(grid.to_string(), "0110\n0110\n0000");
+    }
+
+    #[test]
+    fn cuts_off_regions_at_the_edges() {
+        let mut grid = Grid::new(4, 3, 0);
+        grid.region_mut(2..=9, 1..=usize::MAX).fill(1);
+        assert_eq!(grid.to_string(), "0000\n0011\n0011");
+        assert_eq!(grid.region(3..=5, 0..=2).dim(), (3, 1));
+        assert_eq!(grid.region(4..=5, 0..=2).len(), 0);
+        assert_eq!(grid.region(0..=3, 7..=8).len(), 0);
+        assert_eq!(grid.region(usize::MAX..=usize::MAX, 0..=0).len(), 0);
+    }
+}
@@ -0,0 +1,305 @@
+//! Export of grayscale images and animations, for checking grid puzzles visually.
+//!
+//! Single images are written as PPM or PNG and animations as GIF, chosen by the extension of
+//! the file name. All encoders are pure Rust.
+
+use std::fmt::{self, Display};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::grid::Grid;
+use crate::template::runner::Save;
+
+/// An error which can be returned when saving an image.
+#[derive(Debug)]
+pub enum ImageError {
+    /// The file could not be written.
+    Io(io::Error),
+    /// The encoder rejected the image, e.g. because it is too large for the format.
+    Encoding(String),
+    /// The extension of the file name is not one of the supported formats.
+    UnsupportedFormat(PathBuf),
+    /// A frame of an animation has a different size than the first frame.
+    FrameSize {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+}
+
+impl std::error::Error for ImageError {}
+
+impl Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Io(err) => write!(f, "{err}"),
+            ImageError::Encoding(message) => write!(f, "could not encode image: {message}"),
+            ImageError::UnsupportedFormat(path) => {
+                write!(f, "unsupported image format: {}", path.display())
+            }
+            ImageError::FrameSize { expected, found } => write!(
+                f,
+                "frame is {}x{} pixels, expected {}x{} like the first frame",
+                found.0, found.1, expected.0, expected.1
+            ),
+        }
+    }
+}
+
+impl From<io::Error> for ImageError {
+    fn from(err: io::Error) -> Self {
+        ImageError::Io(err)
+    }
+}
+
+impl From<png::EncodingError> for ImageError {
+    fn from(err: png::EncodingError) -> Self {
+        ImageError::Encoding(err.to_string())
+    }
+}
+
+impl From<gif::EncodingError> for ImageError {
+    fn from(err: gif::EncodingError) -> Self {
+        ImageError::Encoding(err.to_string())
+    }
+}
+
+fn extension(path: &Path) -> Option<String> {
+    let extension = path.extension()?.to_str()?;
+    Some(extension.to_ascii_lowercase())
+}
+
+/// A grayscale image with 8 bits per pixel, where `0` is black and `255` white.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    /// The pixels, row by row.
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    /// Creates an image with one pixel per cell of the grid, converting every cell with `f`.
+    pub fn from_grid<T>(grid: &Grid<T>, f: impl FnMut(&T) -> u8) -> Self {
+        Self {
+            width: grid.width(),
+            height: grid.height(),
+            pixels: grid.iter().map(f).collect(),
+        }
+    }
+
+    /// The width in pixels.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// The height in pixels.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// The value of the pixel at `(x, y)`.
+    pub fn pixel(&self, x: usize, y: usize) -> u8 {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Enlarges the image by drawing every pixel as a square of `factor` by `factor` pixels.
+    pub fn scale(&self, factor: usize) -> Self {
+        let pixels = self
+            .pixels
+            .chunks(self.width.max(1))
+            .flat_map(|row| {
+                let row: Vec<u8> = row
+                    .iter()
+                    .flat_map(|&pixel| std::iter::repeat_n(pixel, factor))
+                    .collect();
+                std::iter::repeat_n(row, factor).flatten()
+            })
+            .collect();
+        Self {
+            width: self.width * factor,
+            height: self.height * factor,
+            pixels,
+        }
+    }
+
+    /// Writes the image as a binary PPM, with equal red, green and blue.
+    pub fn write_ppm(&self, mut writer: impl Write) -> Result<(), ImageError> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+        let rgb: Vec<u8> = self.pixels.iter().flat_map(|&p| [p, p, p]).collect();
+        writer.write_all(&rgb)?;
+        Ok(())
+    }
+
+    /// Writes the image as a grayscale PNG.
+    pub fn write_png(&self, writer: impl Write) -> Result<(), ImageError> {
+        let mut encoder = png::Encoder::new(writer, self.width as u32, self.height as u32);
+        encoder.set_color(png::ColorType::Grayscale);
+        encoder.set_depth(png::BitDepth::Eight);
+        encoder.write_header()?.write_image_data(&self.pixels)?;
+        Ok(())
+    }
+}
+
+impl Save for Image {
+    type Error = ImageError;
+
+    /// Writes a `.ppm` or `.png` file.
+    fn save(&self, path: &Path) -> Result<(), ImageError> {
+        let write = match extension(path).as_deref() {
+            Some("ppm") => Image::write_ppm,
+            Some("png") => Image::write_png,
+            _ => return Err(ImageError::UnsupportedFormat(path.to_owned())),
+        };
+        let mut writer = BufWriter::new(File::create(path)?);
+        write(self, &mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/// A looping animation of grayscale images of equal size.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Animation {
+    frames: Vec<Image>,
+    /// How long every frame is shown, in hundredths of a second.
+    delay: u16,
+}
+
+impl Animation {
+    /// Creates an empty animation showing every frame for `delay` hundredths of a second.
+    pub fn new(delay: u16) -> Self {
+        Self {
+            frames: vec![],
+            delay,
+        }
+    }
+
+    /// Appends a frame, which must have the same size as the first one.
+    pub fn push(&mut self, frame: Image) -> Result<(), ImageError> {
+        if let Some(first) = self.frames.first() {
+            let expected = (first.width, first.height);
+            let found = (frame.width, frame.height);
+            if expected != found {
+                return Err(ImageError::FrameSize { expected, found });
+            }
+        }
+        self.frames.push(frame);
+        Ok(())
+    }
+
+    /// The frames, in order.
+    pub fn frames(&self) -> &[Image] {
+        &self.frames
+    }
+
+    /// Writes the animation as a GIF which repeats forever. Pixels are used as indices into a
+    /// palette of all 256 grays, so no colors are lost.
+    pub fn write_gif(&self, writer: impl Write) -> Result<(), ImageError> {
+        let (width, height) = self.frames.first().map_or((0, 0), |f| (f.width, f.height));
+        let (Ok(width), Ok(height)) = (u16::try_from(width), u16::try_from(height)) else {
+            return Err(ImageError::Encoding(format!(
+                "{width}x{height} pixels is too large for a GIF"
+            )));
+        };
+
+        let palette: Vec<u8> = (0..=255).flat_map(|gray| [gray, gray, gray]).collect();
+        let mut encoder = gif::Encoder::new(writer, width, height, &palette)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+        for image in &self.frames {
+            let frame = gif::Frame {
+                width,
+                height,
+                delay: self.delay,
+                buffer: (&image.pixels).into(),
+                ..gif::Frame::default()
+            };
+            encoder.write_frame(&frame)?;
+        }
+        Ok(())
+    }
+}
+
+impl Save for Animation {
+    type Error = ImageError;
+
+    /// Writes a `.gif` file.
+    fn save(&self, path: &Path) -> Result<(), ImageError> {
+        if extension(path).as_deref() != Some("gif") {
+            return Err(ImageError::UnsupportedFormat(path.to_owned()));
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_gif(&mut writer)?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn checkerboard() -> Image {
+        let grid: Grid<bool> = Grid::parse("#.\n.#\n#.", |ch| Some(ch == '#')).unwrap();
+        Image::from_grid(&grid, |&on| if on { 255 } else { 0 })
+    }
+
+    #[test]
+    fn scales_images() {
+        let image = checkerboard().scale(2);
+        assert_eq!((image.width(), image.height()), (4, 6));
+        assert_eq!(image.pixel(1, 1), 255);
+        assert_eq!(image.pixel(2, 1), 0);
+        assert_eq!(image.pixel(3, 5), 0);
+        assert_eq!(image.pixel(0, 5), 255);
+    }
+
+    #[test]
+    fn writes_ppm() {
+        let mut ppm = vec![];
+        checkerboard().write_ppm(&mut ppm).unwrap();
+        let header = b"P6\n2 3\n255\n";
+        assert_eq!(&ppm[..header.len()], header);
+        assert_eq!(
+            &ppm[header.len()..header.len() + 6],
+            [255, 255, 255, 0, 0, 0]
+        );
+        assert_eq!(ppm.len(), header.len() + 2 * 3 * 3);
+    }
+
+    #[test]
+    fn writes_png() {
+        let mut png = vec![];
+        checkerboard().write_png(&mut png).unwrap();
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+    }
+
+    #[test]
+    fn writes_gif() {
+        let mut animation = Animation::new(10);
+        animation.push(checkerboard()).unwrap();
+        animation.push(checkerboard().scale(1)).unwrap();
+        let err = animation.push(checkerboard().scale(2)).unwrap_err();
+        assert!(matches!(
+            err,
+            ImageError::FrameSize {
+                expected: (2, 3),
+                found: (4, 6)
+            }
+        ));
+        assert_eq!(animation.frames().len(), 2);
+
+        let mut gif = vec![];
+        animation.write_gif(&mut gif).unwrap();
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(gif.last(), Some(&0x3b));
+    }
+
+    #[test]
+    fn rejects_unknown_formats() {
+        let path = Path::new("lights.bmp");
+        let err = checkerboard().save(path).unwrap_err();
+        assert_eq!(err.to_string(), "unsupported image format: lights.bmp");
+        assert!(Animation::new(1).save(Path::new("lights.png")).is_err());
+    }
+}
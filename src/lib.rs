@@ -1,3 +1,16 @@
+pub mod days;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod image;
+pub mod life;
+pub mod math;
+pub mod parse;
+pub mod subsets;
 pub mod template;
+#[cfg(test)]
+mod testing;
+pub mod tsp;
+pub mod vm;
 
 // Use this file to add helper functions and additional modules.
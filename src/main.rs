@@ -1,13 +1,13 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, download, leaderboard, read, scaffold, solve, status, time,
+};
 use args::{parse, AppArguments};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
-#[cfg(feature = "today")]
-use std::process;
+use advent_of_code::template::commands::today;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::{commands::scaffold, leaderboard::Order, Day};
     use std::process;
 
     pub enum AppArguments {
@@ -16,17 +16,20 @@ mod args {
         },
         Read {
             day: Day,
+            part_two: bool,
+            pager: bool,
         },
         Scaffold {
             day: Day,
             download: bool,
-            overwrite: bool,
+            options: scaffold::Options,
         },
         Solve {
             day: Day,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            visualize: Option<String>,
         },
         All {
             release: bool,
@@ -36,8 +39,19 @@ mod args {
             day: Option<Day>,
             store: bool,
         },
+        Status {
+            store: bool,
+        },
+        Leaderboard {
+            file: Option<String>,
+            id: Option<u64>,
+            order: Order,
+            day: Option<Day>,
+        },
         #[cfg(feature = "today")]
-        Today,
+        Today {
+            wait: bool,
+        },
     }
 
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
@@ -57,25 +71,46 @@ mod args {
                     store,
                 }
             }
+            Some("status") => AppArguments::Status {
+                store: args.contains("--store"),
+            },
+            Some("leaderboard") => AppArguments::Leaderboard {
+                file: args.opt_value_from_str("--file")?,
+                id: args.opt_value_from_str("--id")?,
+                order: args.opt_value_from_str("--order")?.unwrap_or(Order::Local),
+                day: args.opt_value_from_str("--day")?,
+            },
             Some("download") => AppArguments::Download {
                 day: args.free_from_str()?,
             },
             Some("read") => AppArguments::Read {
                 day: args.free_from_str()?,
+                part_two: args.contains("--part2"),
+                pager: !args.contains("--no-pager"),
             },
             Some("scaffold") => AppArguments::Scaffold {
                 day: args.free_from_str()?,
                 download: args.contains("--download"),
-                overwrite: args.contains("--overwrite"),
+                options: scaffold::Options {
+                    template: args
+                        .opt_value_from_str("--template")?
+                        .unwrap_or_else(|| "default".into()),
+                    overwrite: args.contains("--overwrite"),
+                    force: args.contains("--force"),
+                    dry_run: args.contains("--dry-run"),
+                },
             },
             Some("solve") => AppArguments::Solve {
                 day: args.free_from_str()?,
                 release: args.contains("--release"),
                 submit: args.opt_value_from_str("--submit")?,
                 dhat: args.contains("--dhat"),
+                visualize: args.opt_value_from_str("--visualize")?,
             },
             #[cfg(feature = "today")]
-            Some("today") => AppArguments::Today,
+            Some("today") => AppArguments::Today {
+                wait: args.contains("--wait"),
+            },
             Some(x) => {
                 eprintln!("Unknown command: {x}");
                 process::exit(1);
@@ -104,16 +139,28 @@ fn main() {
         Ok(args) => match args {
             AppArguments::All { release } => all::handle(release),
             AppArguments::Time { day, all, store } => time::handle(day, all, store),
+            AppArguments::Status { store } => status::handle(store),
+            AppArguments::Leaderboard {
+                file,
+                id,
+                order,
+                day,
+            } => leaderboard::handle(file, id, order, day),
             AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::Read {
+                day,
+                part_two,
+                pager,
+            } => read::handle(day, part_two, pager),
             AppArguments::Scaffold {
                 day,
                 download,
-                overwrite,
+                options,
             } => {
-                scaffold::handle(day, overwrite);
                 if download {
-                    download::handle(day);
+                    scaffold::handle_with_download(day, &options, download::handle);
+                } else {
+                    scaffold::handle(day, &options);
                 }
             }
             AppArguments::Solve {
@@ -121,24 +168,10 @@ fn main() {
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                visualize,
+            } => solve::handle(day, release, dhat, submit, visualize),
             #[cfg(feature = "today")]
-            AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
-                    }
-                    None => {
-                        eprintln!(
-                            "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
-                        );
-                        process::exit(1)
-                    }
-                };
-            }
+            AppArguments::Today { wait } => today::handle(wait),
         },
     };
 }
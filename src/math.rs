@@ -0,0 +1,398 @@
+//! Number theory helpers: prime sieving, divisor functions and modular arithmetic.
+
+use std::fmt::Debug;
+use std::ops::{Add, Div, Mul, Rem, Sub};
+
+/// An unsigned integer type usable with the modular arithmetic functions of this module.
+pub trait Unsigned:
+    Copy
+    + Ord
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Rem<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+
+    /// Computes `self * rhs % m` without overflowing.
+    fn mul_mod(self, rhs: Self, m: Self) -> Self;
+}
+
+macro_rules! impl_unsigned {
+    ($($ty:ty),+) => {
+        $(
+            impl Unsigned for $ty {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_mul(self, rhs: Self) -> Option<Self> {
+                    <$ty>::checked_mul(self, rhs)
+                }
+
+                fn mul_mod(self, rhs: Self, m: Self) -> Self {
+                    (self as u128 * rhs as u128 % m as u128) as Self
+                }
+            }
+        )+
+    };
+}
+
+impl_unsigned!(u8, u16, u32, u64, usize);
+
+impl Unsigned for u128 {
+    const ZERO: Self = 0;
+    const ONE: Self = 1;
+
+    fn checked_mul(self, rhs: Self) -> Option<Self> {
+        u128::checked_mul(self, rhs)
+    }
+
+    fn mul_mod(self, rhs: Self, m: Self) -> Self {
+        if let Some(product) = self.checked_mul(rhs) {
+            return product % m;
+        }
+        // Double-and-add, which only ever adds values below `m`.
+        let (mut a, mut b, mut result) = (self % m, rhs % m, 0);
+        while b > 0 {
+            if b & 1 == 1 {
+                result = add_mod(result, a, m);
+            }
+            a = add_mod(a, a, m);
+            b >>= 1;
+        }
+        result
+    }
+}
+
+/// Computes `(a + b) % m` for `a, b < m` without overflowing.
+fn add_mod<T: Unsigned>(a: T, b: T, m: T) -> T {
+    if a >= m - b {
+        a - (m - b)
+    } else {
+        a + b
+    }
+}
+
+/// Computes `(a - b) % m` for `a, b < m`, wrapping around to stay non-negative.
+fn sub_mod<T: Unsigned>(a: T, b: T, m: T) -> T {
+    if a >= b {
+        a - b
+    } else {
+        m - (b - a)
+    }
+}
+
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+    while b != T::ZERO {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
+/// The least common multiple, or `None` if it overflows.
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+    if a == T::ZERO || b == T::ZERO {
+        return Some(T::ZERO);
+    }
+    (a / gcd(a, b)).checked_mul(b)
+}
+
+/// Computes `base.pow(exp) % m` by repeated squaring.
+pub fn modpow<T: Unsigned>(base: T, mut exp: u128, m: T) -> T {
+    let mut base = base % m;
+    let mut result = T::ONE % m;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result.mul_mod(base, m);
+        }
+        base = base.mul_mod(base, m);
+        exp >>= 1;
+    }
+    result
+}
+
+/// The multiplicative inverse of `a` modulo `m`, if `a` and `m` are coprime.
+pub fn modinv<T: Unsigned>(a: T, m: T) -> Option<T> {
+    // The extended Euclidean algorithm, with the coefficients of `a` kept modulo `m`.
+    let (mut old_r, mut r) = (a % m, m);
+    let (mut old_s, mut s) = (T::ONE % m, T::ZERO);
+    while r != T::ZERO {
+        let q = old_r / r;
+        (old_r, r) = (r, old_r - q * r);
+        (old_s, s) = (s, sub_mod(old_s, (q % m).mul_mod(s, m), m));
+    }
+    (old_r == T::ONE).then_some(old_s)
+}
+
+/// Solves the system of congruences `x ≡ residue (mod modulus)` with the Chinese remainder
+/// theorem. The moduli need not be coprime. Returns the smallest non-negative solution along
+/// with the modulus of all solutions, or `None` if the system has no solution or it overflows.
+pub fn crt<T: Unsigned>(congruences: &[(T, T)]) -> Option<(T, T)> {
+    let mut x = T::ZERO;
+    let mut modulus = T::ONE;
+    for &(residue, m) in congruences {
+        let g = gcd(modulus, m);
+        let diff = sub_mod(residue % m, x % m, m);
+        if diff % g != T::ZERO {
+            return None;
+        }
+        let step = m / g;
+        let k = (diff / g).mul_mod(modinv(modulus / g, step)?, step);
+        let next = (modulus / g).checked_mul(m)?;
+        x = x + modulus.checked_mul(k)?;
+        modulus = next;
+    }
+    Some((x % modulus, modulus))
+}
+
+/// The prime factors of `n` with their multiplicities, by trial division.
+pub fn factorize(mut n: u64) -> Vec<(u64, u32)> {
+    let mut factors = vec![];
+    let mut p = 2;
+    while p * p <= n {
+        if n.is_multiple_of(p) {
+            let mut k = 0;
+            while n.is_multiple_of(p) {
+                n /= p;
+                k += 1;
+            }
+            factors.push((p, k));
+        }
+        p += if p == 2 { 1 } else { 2 };
+    }
+    if n > 1 {
+        factors.push((n, 1));
+    }
+    factors
+}
+
+/// All divisors of `n`, in ascending order.
+pub fn divisors(n: u64) -> Vec<u64> {
+    divisors_of(&factorize(n))
+}
+
+/// The sum of all divisors of `n`.
+pub fn sigma(n: u64) -> u64 {
+    sigma_of(&factorize(n))
+}
+
+fn divisors_of<T: Unsigned>(factors: &[(T, u32)]) -> Vec<T> {
+    let mut divisors = vec![T::ONE];
+    for &(p, k) in factors {
+        let count = divisors.len();
+        let mut power = T::ONE;
+        for _ in 0..k {
+            power = power * p;
+            for i in 0..count {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+    divisors.sort_unstable();
+    divisors
+}
+
+fn sigma_of<T: Unsigned>(factors: &[(T, u32)]) -> T {
+    factors
+        .iter()
+        .map(|&(p, k)| {
+            // 1 + p + p^2 + ... + p^k
+            (0..k).fold(T::ONE, |sum, _| sum * p + T::ONE)
+        })
+        .fold(T::ONE, |product, sum| product * sum)
+}
+
+/// A sieve of the smallest prime factor of every number up to a limit, which makes primality
+/// checks and factorizations below the limit cheap.
+pub struct Sieve {
+    smallest_factor: Vec<u32>,
+}
+
+impl Sieve {
+    /// Sieves all numbers up to and including `limit`.
+    pub fn new(limit: usize) -> Self {
+        assert!(
+            limit <= u32::MAX as usize,
+            "sieve limit {limit} is too large"
+        );
+        let mut smallest_factor = vec![0u32; limit + 1];
+        let mut primes = vec![];
+        // Linear sieve: every composite is crossed out exactly once, by its smallest factor.
+        for n in 2..=limit {
+            if smallest_factor[n] == 0 {
+                smallest_factor[n] = n as u32;
+                primes.push(n);
+            }
+            let spf = smallest_factor[n] as usize;
+            for &p in primes.iter().take_while(|&&p| p <= spf && p * n <= limit) {
+                smallest_factor[p * n] = p as u32;
+            }
+        }
+        Self { smallest_factor }
+    }
+
+    pub fn limit(&self) -> usize {
+        self.smallest_factor.len() - 1
+    }
+
+    pub fn is_prime(&self, n: usize) -> bool {
+        n >= 2 && self.smallest_factor[n] as usize == n
+    }
+
+    /// Iterates over all primes up to the limit.
+    pub fn primes(&self) -> impl Iterator<Item = usize> + '_ {
+        (2..=self.limit()).filter(|n| self.is_prime(*n))
+    }
+
+    /// The prime factors of `n` with their multiplicities.
+    ///
+    /// # Panics
+    /// Panics if `n` is zero or above the limit.
+    pub fn factorize(&self, mut n: usize) -> Vec<(usize, u32)> {
+        assert!(n > 0, "cannot factorize zero");
+        let mut factors: Vec<(usize, u32)> = vec![];
+        while n > 1 {
+            let p = self.smallest_factor[n] as usize;
+            match factors.last_mut() {
+                Some((q, k)) if *q == p => *k += 1,
+                _ => factors.push((p, 1)),
+            }
+            n /= p;
+        }
+        factors
+    }
+
+    /// All divisors of `n`, in ascending order.
+    pub fn divisors(&self, n: usize) -> Vec<usize> {
+        divisors_of(&self.factorize(n))
+    }
+
+    /// The sum of all divisors of `n`.
+    pub fn sigma(&self, mut n: usize) -> usize {
+        assert!(n > 0, "cannot factorize zero");
+        let mut product = 1;
+        while n > 1 {
+            let p = self.smallest_factor[n] as usize;
+            // 1 + p + p^2 + ... for every factor p of n
+            let mut sum = 1;
+            while n.is_multiple_of(p) {
+                n /= p;
+                sum = sum * p + 1;
+            }
+            product *= sum;
+        }
+        product
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A deterministic stream of pseudo-random numbers for property tests.
+    fn random(seed: u64) -> impl Iterator<Item = u64> {
+        let mut state = seed;
+        std::iter::repeat_with(move || {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            state >> 11
+        })
+    }
+
+    #[test]
+    fn sieves_primes() {
+        let sieve = Sieve::new(1000);
+        let naive: Vec<usize> = (2..=1000).filter(|n| (2..*n).all(|d| n % d != 0)).collect();
+        assert_eq!(sieve.primes().collect::<Vec<_>>(), naive);
+        assert!(!sieve.is_prime(0) && !sieve.is_prime(1));
+        assert_eq!(sieve.factorize(1), vec![]);
+        assert_eq!(sieve.factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        assert_eq!(
+            factorize(600851475143),
+            vec![(71, 1), (839, 1), (1471, 1), (6857, 1)]
+        );
+    }
+
+    #[test]
+    fn divisor_functions() {
+        let sieve = Sieve::new(2000);
+        for n in 1..=2000 {
+            let naive: Vec<usize> = (1..=n).filter(|d| n % d == 0).collect();
+            assert_eq!(sieve.divisors(n), naive);
+            assert_eq!(sieve.sigma(n), naive.iter().sum::<usize>());
+            assert_eq!(divisors(n as u64).len(), naive.len());
+            assert_eq!(sigma(n as u64) as usize, sieve.sigma(n));
+        }
+    }
+
+    #[test]
+    fn gcd_and_lcm() {
+        let mut rng = random(1);
+        for _ in 0..1000 {
+            let (a, b) = (rng.next().unwrap() % 100_000, rng.next().unwrap() % 100_000);
+            let g = gcd(a, b);
+            if g == 0 {
+                assert_eq!((a, b), (0, 0));
+                continue;
+            }
+            assert_eq!((a % g, b % g), (0, 0));
+            assert_eq!(gcd(a / g, b / g), 1);
+            assert_eq!(lcm(a, b).unwrap() * g, a * b);
+        }
+        assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+    }
+
+    #[test]
+    fn modular_arithmetic() {
+        let mut rng = random(2);
+        for _ in 0..1000 {
+            let m = rng.next().unwrap() % 1000 + 1;
+            let base = rng.next().unwrap();
+            let exp = rng.next().unwrap() % 50;
+            let naive = (0..exp).fold(1 % m, |acc, _| acc * (base % m) % m);
+            assert_eq!(modpow(base, exp as u128, m), naive);
+
+            let a = rng.next().unwrap() % m;
+            match modinv(a, m) {
+                Some(inv) => assert_eq!(a * inv % m, 1 % m),
+                None => assert_ne!(gcd(a, m), 1),
+            }
+        }
+
+        // Large moduli, where products overflow even u128.
+        let m = u128::MAX - 158; // the largest prime below 2^128
+        let a = u128::MAX / 3;
+        assert_eq!(modpow(a, m - 1, m), 1);
+        assert_eq!(a.mul_mod(modinv(a, m).unwrap(), m), 1);
+        assert_eq!(modpow(2u64, 64, u64::MAX), 1);
+    }
+
+    #[test]
+    fn chinese_remainders() {
+        let mut rng = random(3);
+        for _ in 0..200 {
+            let congruences: Vec<(u64, u64)> = (0..3)
+                .map(|_| {
+                    let m = rng.next().unwrap() % 30 + 1;
+                    (rng.next().unwrap() % m, m)
+                })
+                .collect();
+            let naive = (0..30u64.pow(3)).find(|x| congruences.iter().all(|(r, m)| x % m == *r));
+            match crt(&congruences) {
+                Some((x, modulus)) => {
+                    assert_eq!(Some(x), naive);
+                    let expected = congruences.iter().fold(1, |l, (_, m)| lcm(l, *m).unwrap());
+                    assert_eq!(modulus, expected);
+                }
+                None => assert_eq!(naive, None),
+            }
+        }
+        assert_eq!(crt::<u64>(&[]), Some((0, 1)));
+    }
+}
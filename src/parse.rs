@@ -0,0 +1,543 @@
+//! Helpers for parsing puzzle input, with errors that point at the offending input.
+//!
+//! Errors are reported as a [`ParseError`], which shows the line and column of the problem along
+//! with a caret under the input:
+//!
+//! ```text
+//! line 2, column 16: invalid digit found in string
+//!   |
+//! 2 | Dancer can fly x6 km/s for 11 seconds, but then must rest for 162 seconds.
+//!   |                ^^
+//! ```
+
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// An error in the input, located by line and column (both starting at 1).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub width: usize,
+    pub text: String,
+    pub message: String,
+}
+
+impl std::error::Error for ParseError {}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let gutter = " ".repeat(self.line.to_string().len());
+        let indent = " ".repeat(self.column.saturating_sub(1));
+        let carets = "^".repeat(self.width.max(1));
+        writeln!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{gutter} | {indent}{carets}")
+    }
+}
+
+/// A line of the input, which remembers its line number for error reporting.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Line<'a> {
+    /// The line number, starting at 1.
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Creates an error pointing at `width` characters from the 1-based `column`.
+    pub fn error_at_column(
+        &self,
+        column: usize,
+        width: usize,
+        message: impl Display,
+    ) -> ParseError {
+        ParseError {
+            line: self.number,
+            column,
+            width,
+            text: self.text.to_string(),
+            message: message.to_string(),
+        }
+    }
+
+    /// Creates an error pointing at `fragment`, which must be a slice of this line.
+    pub fn error_at(&self, fragment: &str, message: impl Display) -> ParseError {
+        let offset = (fragment.as_ptr() as usize)
+            .checked_sub(self.text.as_ptr() as usize)
+            .filter(|offset| offset + fragment.len() <= self.text.len())
+            .expect("fragment is not part of the line");
+        let column = self.text[..offset].chars().count() + 1;
+        self.error_at_column(column, fragment.chars().count(), message)
+    }
+
+    /// Creates an error pointing at the whole line.
+    pub fn error(&self, message: impl Display) -> ParseError {
+        self.error_at(self.text, message)
+    }
+
+    /// Parses `fragment`, which must be a slice of this line, reporting failures at its position.
+    pub fn parse<T>(&self, fragment: &str) -> Result<T, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        fragment.parse().map_err(|err| self.error_at(fragment, err))
+    }
+
+    /// Parses every integer in the line, ignoring everything in between.
+    /// A `-` directly in front of the digits makes the integer negative.
+    pub fn ints<T>(&self) -> Result<Vec<T>, ParseError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        int_spans(self.text).map(|span| self.parse(span)).collect()
+    }
+
+    /// Splits the line into the fields of a `pattern`, where every `{}` in the pattern
+    /// stands for a field and all other text must match literally, see [`Pattern`].
+    ///
+    /// This is an uncached convenience which compiles the pattern on every call. When parsing
+    /// many lines with the same pattern, use [`parse!`](crate::parse!) instead.
+    ///
+    /// # Panics
+    /// Panics if the pattern does not contain exactly `N` fields.
+    pub fn fields<const N: usize>(&self, pattern: &str) -> Result<[&'a str; N], ParseError> {
+        Pattern::new(pattern).parse(*self)
+    }
+}
+
+/// A line pattern such as `"{name} can fly {speed} km/s"`, where every `{}` or `{name}` is a
+/// field and all other text must match literally. The names only serve to label errors.
+///
+/// A field extends up to the next occurrence of the literal text following it. If the rest of
+/// the line does not match, or the fields do not convert to the requested types, later
+/// occurrences are tried in turn. Use [`parse!`](crate::parse!) to compile a pattern only once.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Pattern {
+    prefix: String,
+    /// The name of each field and the literal text following it.
+    fields: Vec<(String, String)>,
+}
+
+impl Pattern {
+    /// Compiles a pattern.
+    ///
+    /// # Panics
+    /// Panics if a brace is unbalanced, or if two fields are adjacent which makes them ambiguous.
+    pub fn new(pattern: &str) -> Self {
+        let mut parts = pattern.split('{');
+        let prefix = parts.next().unwrap_or_default().to_string();
+        assert!(!prefix.contains('}'), "unbalanced brace in {pattern:?}");
+
+        let fields: Vec<_> = parts
+            .map(|part| {
+                let (name, literal) = part
+                    .split_once('}')
+                    .unwrap_or_else(|| panic!("unclosed field in {pattern:?}"));
+                assert!(!literal.contains('}'), "unbalanced brace in {pattern:?}");
+                (name.to_string(), literal.to_string())
+            })
+            .collect();
+
+        let adjacent = fields.iter().rev().skip(1).any(|(_, lit)| lit.is_empty());
+        assert!(!adjacent, "adjacent fields in {pattern:?}");
+        Self { prefix, fields }
+    }
+
+    /// The number of fields.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Matches the line against the pattern and converts the fields, usually into a tuple.
+    /// If no match converts, the error which is furthest into the line is returned.
+    ///
+    /// # Panics
+    /// Panics if `T` does not have as many elements as the pattern has fields.
+    pub fn parse<'a, T: FromFields<'a>>(&self, line: Line<'a>) -> Result<T, ParseError> {
+        let mut result = None;
+        let mut furthest_error: Option<ParseError> = None;
+
+        if let Some(rest) = line.text.strip_prefix(self.prefix.as_str()) {
+            let mut fields = Vec::with_capacity(self.len());
+            self.each_match(rest, &mut fields, &mut |fields| {
+                match T::from_fields(&line, self, fields) {
+                    Ok(value) => result = Some(value),
+                    Err(err) => {
+                        if furthest_error
+                            .as_ref()
+                            .is_none_or(|e| err.column > e.column)
+                        {
+                            furthest_error = Some(err);
+                        }
+                    }
+                }
+                result.is_some()
+            });
+        }
+
+        match (result, furthest_error) {
+            (Some(value), _) => Ok(value),
+            (None, Some(err)) => Err(err),
+            (None, None) => Err(self.mismatch(&line)),
+        }
+    }
+
+    /// Calls `f` with the fields of every way the pattern matches `rest`, until it returns true.
+    fn each_match<'a>(
+        &self,
+        rest: &'a str,
+        fields: &mut Vec<&'a str>,
+        f: &mut dyn FnMut(&[&'a str]) -> bool,
+    ) -> bool {
+        let Some((_, literal)) = self.fields.get(fields.len()) else {
+            return rest.is_empty() && f(fields);
+        };
+
+        let ends: Vec<usize> = if literal.is_empty() {
+            vec![rest.len()]
+        } else {
+            (1..rest.len())
+                .filter(|i| rest.is_char_boundary(*i) && rest[*i..].starts_with(literal.as_str()))
+                .collect()
+        };
+
+        for end in ends.into_iter().filter(|end| *end > 0) {
+            fields.push(&rest[..end]);
+            if self.each_match(&rest[end + literal.len()..], fields, f) {
+                return true;
+            }
+            fields.pop();
+        }
+        false
+    }
+
+    /// Locates why the line does not match, following the shortest possible fields.
+    fn mismatch(&self, line: &Line) -> ParseError {
+        let mut rest = match expect_literal(line, line.text, &self.prefix) {
+            Ok(rest) => rest,
+            Err(err) => return err,
+        };
+
+        for (_, literal) in &self.fields {
+            if rest.is_empty() {
+                return line.error_at(rest, "expected a field");
+            }
+            if literal.is_empty() {
+                rest = "";
+                break;
+            }
+            let Some(end) = rest.get(1..).and_then(|tail| tail.find(literal.as_str())) else {
+                // Point at the first mismatch after the longest matching prefix.
+                let mismatch = (1..literal.len())
+                    .rev()
+                    .filter(|k| literal.is_char_boundary(*k))
+                    .find_map(|k| Some(rest.get(1..)?.find(&literal[..k])? + 1 + k))
+                    .unwrap_or(rest.len());
+                return error_at_char(line, rest, mismatch, literal);
+            };
+            rest = &rest[end + 1 + literal.len()..];
+        }
+
+        if rest.is_empty() {
+            line.error("line does not match the pattern")
+        } else {
+            line.error_at(rest, "unexpected trailing input")
+        }
+    }
+}
+
+fn expect_literal<'a>(line: &Line, rest: &'a str, literal: &str) -> Result<&'a str, ParseError> {
+    rest.strip_prefix(literal).ok_or_else(|| {
+        let mismatch = rest
+            .char_indices()
+            .zip(literal.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(rest.len(), |((i, _), _)| i);
+        error_at_char(line, rest, mismatch, literal)
+    })
+}
+
+/// Reports that `literal` was expected at the character at `offset` into `rest`.
+fn error_at_char(line: &Line, rest: &str, offset: usize, literal: &str) -> ParseError {
+    let found = rest[offset..].chars().next().map_or(0, char::len_utf8);
+    line.error_at(
+        &rest[offset..offset + found],
+        format!("expected {literal:?}"),
+    )
+}
+
+/// A type which can be converted from a single field of a [`Pattern`].
+pub trait FromField<'a>: Sized {
+    fn from_field(field: &'a str) -> Result<Self, String>;
+}
+
+impl<'a> FromField<'a> for &'a str {
+    fn from_field(field: &'a str) -> Result<Self, String> {
+        Ok(field)
+    }
+}
+
+macro_rules! impl_from_field {
+    ($($ty:ty),+) => {
+        $(
+            impl FromField<'_> for $ty {
+                fn from_field(field: &str) -> Result<Self, String> {
+                    field.parse().map_err(|err: <$ty as FromStr>::Err| err.to_string())
+                }
+            }
+        )+
+    };
+}
+
+impl_from_field!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, bool, char, String
+);
+
+/// A type which can be converted from all fields of a [`Pattern`], such as tuples and arrays
+/// of [`FromField`] types.
+pub trait FromFields<'a>: Sized {
+    fn from_fields(
+        line: &Line<'a>,
+        pattern: &Pattern,
+        fields: &[&'a str],
+    ) -> Result<Self, ParseError>;
+}
+
+/// Converts the field at `index`, reporting failures at its position and by its name.
+fn convert<'a, T: FromField<'a>>(
+    line: &Line<'a>,
+    pattern: &Pattern,
+    fields: &[&'a str],
+    index: usize,
+) -> Result<T, ParseError> {
+    T::from_field(fields[index]).map_err(|err| {
+        let name = &pattern.fields[index].0;
+        if name.is_empty() {
+            line.error_at(fields[index], err)
+        } else {
+            line.error_at(fields[index], format!("{name}: {err}"))
+        }
+    })
+}
+
+impl<'a, T: FromField<'a>, const N: usize> FromFields<'a> for [T; N] {
+    fn from_fields(
+        line: &Line<'a>,
+        pattern: &Pattern,
+        fields: &[&'a str],
+    ) -> Result<Self, ParseError> {
+        assert_eq!(fields.len(), N, "pattern does not have {N} fields");
+        let values = (0..N)
+            .map(|i| convert(line, pattern, fields, i))
+            .collect::<Result<Vec<T>, _>>()?;
+        match values.try_into() {
+            Ok(values) => Ok(values),
+            Err(_) => unreachable!(),
+        }
+    }
+}
+
+macro_rules! impl_from_fields {
+    ($n:literal: $($ty:ident $i:tt),+) => {
+        impl<'a, $($ty: FromField<'a>),+> FromFields<'a> for ($($ty,)+) {
+            fn from_fields(
+                line: &Line<'a>,
+                pattern: &Pattern,
+                fields: &[&'a str],
+            ) -> Result<Self, ParseError> {
+                assert_eq!(fields.len(), $n, "pattern does not have {} fields", $n);
+                Ok(($(convert::<$ty>(line, pattern, fields, $i)?,)+))
+            }
+        }
+    };
+}
+
+impl_from_fields!(1: A 0);
+impl_from_fields!(2: A 0, B 1);
+impl_from_fields!(3: A 0, B 1, C 2);
+impl_from_fields!(4: A 0, B 1, C 2, D 3);
+impl_from_fields!(5: A 0, B 1, C 2, D 3, E 4);
+impl_from_fields!(6: A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_fields!(7: A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_fields!(8: A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Parses a [`Line`] against a pattern, converting its fields into the inferred tuple or
+/// array type. The [`Pattern`] is compiled on first use and cached for later calls.
+///
+/// For example, `parse!(line, "{x},{y} -> {label}")` can produce a `(i32, i32, &str)`.
+#[macro_export]
+macro_rules! parse {
+    ($line:expr, $pattern:literal) => {{
+        static PATTERN: ::std::sync::OnceLock<$crate::parse::Pattern> =
+            ::std::sync::OnceLock::new();
+        PATTERN
+            .get_or_init(|| $crate::parse::Pattern::new($pattern))
+            .parse($line)
+    }};
+}
+
+/// Iterates over the non-empty lines of the input, along with their line numbers.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    numbered_lines(input, 1).filter(|line| !line.text.is_empty())
+}
+
+/// Splits the input into sections separated by blank lines, each a list of its lines.
+pub fn sections(input: &str) -> impl Iterator<Item = Vec<Line<'_>>> {
+    let mut lines = numbered_lines(input, 1).peekable();
+    std::iter::from_fn(move || {
+        while lines.next_if(|line| line.text.trim().is_empty()).is_some() {}
+        let mut section = vec![];
+        while let Some(line) = lines.next_if(|line| !line.text.trim().is_empty()) {
+            section.push(line);
+        }
+        (!section.is_empty()).then_some(section)
+    })
+}
+
+/// Parses every integer in the input, regardless of lines, see [`Line::ints`].
+pub fn ints<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    let mut ints = vec![];
+    for line in lines(input) {
+        ints.extend(line.ints()?);
+    }
+    Ok(ints)
+}
+
+fn numbered_lines(input: &str, first: usize) -> impl Iterator<Item = Line<'_>> {
+    input.lines().enumerate().map(move |(i, text)| Line {
+        number: first + i,
+        text: text.trim_end_matches('\r'),
+    })
+}
+
+fn int_spans(text: &str) -> impl Iterator<Item = &str> {
+    let bytes = text.as_bytes();
+    let mut i = 0;
+    std::iter::from_fn(move || {
+        while i < bytes.len() && !bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == bytes.len() {
+            return None;
+        }
+        let start = if i > 0 && bytes[i - 1] == b'-' {
+            i - 1
+        } else {
+            i
+        };
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        Some(&text[start..i])
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REINDEER: &str =
+        "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.";
+    const PATTERN: &str = "{} can fly {} km/s for {} seconds, but then must rest for {} seconds.";
+
+    fn line(text: &str) -> Line<'_> {
+        Line { number: 3, text }
+    }
+
+    #[test]
+    fn extracts_integers() {
+        assert_eq!(line(REINDEER).ints::<u32>(), Ok(vec![14, 10, 127]));
+        assert_eq!(line("x=-3, y=4..-12").ints::<i32>(), Ok(vec![-3, 4, -12]));
+        assert_eq!(ints::<u8>("1 2\n\n3x4"), Ok(vec![1, 2, 3, 4]));
+
+        let err = line("a 300 b").ints::<u8>().unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (3, 3, 3));
+    }
+
+    #[test]
+    fn splits_fields() {
+        let comet = line(REINDEER);
+        let [name, speed, stamina, rest] = comet.fields(PATTERN).unwrap();
+        assert_eq!([name, speed, stamina, rest], ["Comet", "14", "10", "127"]);
+        assert_eq!(comet.parse::<u32>(rest), Ok(127));
+
+        let [a, b] = line("a -> b -> c").fields("{} -> {}").unwrap();
+        assert_eq!((a, b), ("a", "b -> c"));
+    }
+
+    #[test]
+    fn reports_locations() {
+        let typo = "Comet can fly 14 km/h for 10 seconds, but then must rest for 127 seconds.";
+        let err = line(typo).fields::<4>(PATTERN).unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (3, 21, 1));
+        assert_eq!(err.message, "expected \" km/s for \"");
+
+        let garbled = REINDEER.replace("14", "1x");
+        let garbled = line(&garbled);
+        let [_, speed, _, _] = garbled.fields(PATTERN).unwrap();
+        let err = garbled.parse::<u32>(speed).unwrap_err();
+        let rendered = err.to_string();
+        let rendered: Vec<_> = rendered.lines().collect();
+        assert_eq!(
+            rendered[0],
+            "line 3, column 15: invalid digit found in string"
+        );
+        assert_eq!(rendered[3], "  |               ^^");
+
+        let err = line("Comet can fly").fields::<4>(PATTERN).unwrap_err();
+        assert_eq!(err.column, 14);
+        let err = line("x -> y!").fields::<2>("{} -> {}.").unwrap_err();
+        assert_eq!(err.message, "expected \".\"");
+    }
+
+    #[test]
+    fn matches_patterns() {
+        let line = line("turn off 499,499 through 500,500");
+        let (op, x, y, _, _): (&str, u32, u32, u32, u32) =
+            crate::parse!(line, "{op} {x},{y} through {x2},{y2}").unwrap();
+        assert_eq!((op, x, y), ("turn off", 499, 499));
+
+        let err = crate::parse!(line, "{op} {x},{y} through {x2},{y2}")
+            .map(|(_, _, _, _, _): (&str, u32, u32, u32, u8)| ())
+            .unwrap_err();
+        assert_eq!((err.column, err.width), (30, 3));
+        assert_eq!(err.message, "y2: number too large to fit in target type");
+
+        let err = Pattern::new("{} -> {}")
+            .parse::<[&str; 2]>(line)
+            .unwrap_err();
+        assert_eq!((err.column, err.message.as_str()), (6, "expected \" -> \""));
+        assert_eq!(Pattern::new("{a}-{b}").len(), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "adjacent fields")]
+    fn rejects_ambiguous_patterns() {
+        Pattern::new("{a}{b}");
+    }
+
+    #[test]
+    fn splits_sections() {
+        let input = "a\nb\n\n\nc\n";
+        let sections: Vec<Vec<_>> = sections(input)
+            .map(|s| s.iter().map(|l| (l.number, l.text)).collect())
+            .collect();
+        assert_eq!(sections, vec![vec![(1, "a"), (2, "b")], vec![(5, "c")]]);
+    }
+}
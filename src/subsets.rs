@@ -0,0 +1,161 @@
+//! Enumeration of the subsets of a list of numbers that add up to a target, as needed by
+//! packing and partitioning puzzles.
+//!
+//! Subsets are reported as the indices of their items, in increasing order. Branches that can no
+//! longer reach the target are pruned, so the cost depends on the number of matching subsets
+//! rather than on all `2^n` subsets.
+
+use std::ops::ControlFlow;
+
+/// Calls `visit` with every subset of `items` that sums to `target`, in lexicographic order of
+/// their indices. Stops as soon as `visit` breaks, returning the value it broke with.
+pub fn subsets_with_sum<B>(
+    items: &[u64],
+    target: u64,
+    mut visit: impl FnMut(&[usize]) -> ControlFlow<B>,
+) -> Option<B> {
+    Search::new(items, None).run(target, &mut visit)
+}
+
+/// Like [`subsets_with_sum`], but only visits the subsets with exactly `len` items.
+pub fn subsets_with_sum_of_len<B>(
+    items: &[u64],
+    target: u64,
+    len: usize,
+    mut visit: impl FnMut(&[usize]) -> ControlFlow<B>,
+) -> Option<B> {
+    Search::new(items, Some(len)).run(target, &mut visit)
+}
+
+struct Search<'a> {
+    items: &'a [u64],
+    /// `suffix[i]` is the sum of `items[i..]`, the most that can still be added from `i` on.
+    suffix: Vec<u64>,
+    len: Option<usize>,
+    chosen: Vec<usize>,
+}
+
+impl<'a> Search<'a> {
+    fn new(items: &'a [u64], len: Option<usize>) -> Self {
+        let mut suffix = vec![0; items.len() + 1];
+        for i in (0..items.len()).rev() {
+            suffix[i] = suffix[i + 1] + items[i];
+        }
+        Self {
+            items,
+            suffix,
+            len,
+            chosen: vec![],
+        }
+    }
+
+    fn run<B>(
+        mut self,
+        target: u64,
+        visit: &mut impl FnMut(&[usize]) -> ControlFlow<B>,
+    ) -> Option<B> {
+        self.extend(0, target, visit).break_value()
+    }
+
+    fn extend<B>(
+        &mut self,
+        start: usize,
+        remaining: u64,
+        visit: &mut impl FnMut(&[usize]) -> ControlFlow<B>,
+    ) -> ControlFlow<B> {
+        let full = self.len.is_some_and(|len| self.chosen.len() >= len);
+        if remaining == 0 && self.len.is_none_or(|len| self.chosen.len() == len) {
+            visit(&self.chosen)?;
+        }
+        if full {
+            return ControlFlow::Continue(());
+        }
+
+        for i in start..self.items.len() {
+            if self.suffix[i] < remaining {
+                break;
+            }
+            if self.items[i] > remaining {
+                continue;
+            }
+            self.chosen.push(i);
+            let flow = self.extend(i + 1, remaining - self.items[i], visit);
+            self.chosen.pop();
+            flow?;
+        }
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(items: &[u64], target: u64, len: Option<usize>) -> Vec<Vec<usize>> {
+        let mut subsets = vec![];
+        let visit = |subset: &[usize]| {
+            subsets.push(subset.to_vec());
+            ControlFlow::<()>::Continue(())
+        };
+        match len {
+            Some(len) => subsets_with_sum_of_len(items, target, len, visit),
+            None => subsets_with_sum(items, target, visit),
+        };
+        subsets
+    }
+
+    fn brute_force(items: &[u64], target: u64, len: Option<usize>) -> Vec<Vec<usize>> {
+        let mut subsets: Vec<Vec<usize>> = (0..1_u32 << items.len())
+            .map(|mask| (0..items.len()).filter(|i| mask & (1 << i) != 0).collect())
+            .filter(|subset: &Vec<usize>| subset.iter().map(|&i| items[i]).sum::<u64>() == target)
+            .filter(|subset| len.is_none_or(|len| subset.len() == len))
+            .collect();
+        subsets.sort();
+        subsets
+    }
+
+    #[test]
+    fn finds_all_subsets() {
+        let items = [20, 15, 10, 5, 5];
+        assert_eq!(
+            collect(&items, 25, None),
+            vec![vec![0, 3], vec![0, 4], vec![1, 2], vec![1, 3, 4]]
+        );
+        assert_eq!(collect(&items, 25, Some(3)), vec![vec![1, 3, 4]]);
+        assert_eq!(collect(&items, 0, None), vec![Vec::<usize>::new()]);
+        assert!(collect(&items, 100, None).is_empty());
+    }
+
+    #[test]
+    fn matches_brute_force() {
+        // A small linear congruential generator keeps the cases deterministic.
+        let mut state = 17_u64;
+        let mut next = |bound: u64| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (state >> 33) % bound
+        };
+        for _ in 0..50 {
+            let items: Vec<u64> = (0..12).map(|_| next(10)).collect();
+            let target = next(40);
+            let len = (next(2) == 0).then(|| next(6) as usize);
+            assert_eq!(
+                collect(&items, target, len),
+                brute_force(&items, target, len),
+                "{items:?} {target} {len:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn stops_early() {
+        let mut visited = 0;
+        let first = subsets_with_sum(&[1, 2, 3, 4], 5, |subset| {
+            visited += 1;
+            ControlFlow::Break(subset.to_vec())
+        });
+        assert_eq!(first, Some(vec![0, 3]));
+        assert_eq!(visited, 1);
+    }
+}
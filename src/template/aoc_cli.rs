@@ -1,6 +1,9 @@
 /// Wrapper module around the "aoc-cli" command-line.
 use std::{
+    env,
     fmt::Display,
+    fs,
+    path::{Path, PathBuf},
     process::{Command, Output, Stdio},
 };
 
@@ -33,20 +36,54 @@ pub fn check() -> Result<(), AocCommandError> {
     Ok(())
 }
 
-pub fn read(day: Day) -> Result<Output, AocCommandError> {
+/// Reads the Advent of Code session cookie from one of the locations aoc-cli reads it from.
+pub fn session() -> Option<String> {
+    if let Ok(session) = env::var("ADVENT_OF_CODE_SESSION") {
+        if !session.trim().is_empty() {
+            return Some(session.trim().into());
+        }
+    }
+
+    let home = env::var_os("HOME").or_else(|| env::var_os("USERPROFILE"));
+    let config = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| home.as_ref().map(|h| Path::new(h).join(".config")));
+
+    [
+        home.map(|h| Path::new(&h).join(".adventofcode.session")),
+        config.map(|c| c.join("adventofcode.session")),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(|path| fs::read_to_string(path).ok())
+    .map(|session| session.trim().to_string())
+    .find(|session| !session.is_empty())
+}
+
+/// Checks whether an Advent of Code session cookie is configured.
+pub fn has_session() -> bool {
+    session().is_some()
+}
+
+/// Downloads only the puzzle description for a day, leaving the input untouched.
+pub fn download_puzzle(day: Day) -> Result<Output, AocCommandError> {
     let puzzle_path = get_puzzle_path(day);
 
     let args = build_args(
-        "read",
+        "download",
         &[
-            "--description-only".into(),
+            "--overwrite".into(),
+            "--puzzle-only".into(),
             "--puzzle-file".into(),
-            puzzle_path,
+            puzzle_path.to_string(),
         ],
         day,
     );
 
-    call_aoc_cli(&args)
+    let output = call_aoc_cli(&args)?;
+    println!("---");
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(output)
 }
 
 pub fn download(day: Day) -> Result<Output, AocCommandError> {
@@ -84,11 +121,11 @@ fn get_input_path(day: Day) -> String {
     format!("data/inputs/{day}.txt")
 }
 
-fn get_puzzle_path(day: Day) -> String {
+pub fn get_puzzle_path(day: Day) -> String {
     format!("data/puzzles/{day}.md")
 }
 
-fn get_year() -> Option<u16> {
+pub fn get_year() -> Option<u16> {
     match std::env::var("AOC_YEAR") {
         Ok(x) => x.parse().ok().or(None),
         Err(_) => None,
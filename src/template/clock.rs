@@ -0,0 +1,124 @@
+/// Abstraction over the system time, so that time-dependent commands can be tested without waiting.
+use std::{thread, time::Duration};
+
+use chrono::{DateTime, Utc};
+
+/// A source of the current time that can also block for a duration.
+pub trait Clock {
+    /// Returns the current time.
+    fn now(&self) -> DateTime<Utc>;
+
+    /// Blocks the current thread for `duration`.
+    fn sleep(&self, duration: Duration);
+}
+
+/// The [`Clock`] backed by the operating system.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> DateTime<Utc> {
+        Utc::now()
+    }
+
+    fn sleep(&self, duration: Duration) {
+        thread::sleep(duration);
+    }
+}
+
+/// Calls `f` until it succeeds, sleeping between attempts with an exponentially growing delay.
+/// The delay starts at `initial_delay` and is capped at `max_delay`.
+/// Returns the last error if all `attempts` fail.
+pub fn retry_with_backoff<T, E>(
+    clock: &impl Clock,
+    attempts: u32,
+    initial_delay: Duration,
+    max_delay: Duration,
+    mut f: impl FnMut(u32) -> Result<T, E>,
+) -> Result<T, E> {
+    let mut delay = initial_delay;
+    let mut attempt = 1;
+    loop {
+        match f(attempt) {
+            Ok(value) => return Ok(value),
+            Err(e) if attempt >= attempts => return Err(e),
+            Err(_) => {
+                clock.sleep(delay);
+                delay = (delay * 2).min(max_delay);
+                attempt += 1;
+            }
+        }
+    }
+}
+
+#[cfg(feature = "test_lib")]
+pub mod tests {
+    use super::{retry_with_backoff, Clock};
+    use chrono::{DateTime, Utc};
+    use std::{cell::RefCell, time::Duration};
+
+    /// A [`Clock`] that advances instantly when slept on and records every sleep.
+    pub struct FakeClock {
+        pub now: RefCell<DateTime<Utc>>,
+        pub sleeps: RefCell<Vec<Duration>>,
+    }
+
+    impl FakeClock {
+        pub fn new(now: DateTime<Utc>) -> Self {
+            Self {
+                now: RefCell::new(now),
+                sleeps: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    impl Clock for FakeClock {
+        fn now(&self) -> DateTime<Utc> {
+            *self.now.borrow()
+        }
+
+        fn sleep(&self, duration: Duration) {
+            *self.now.borrow_mut() += chrono::Duration::from_std(duration).unwrap();
+            self.sleeps.borrow_mut().push(duration);
+        }
+    }
+
+    #[test]
+    fn retries_with_exponential_backoff() {
+        let clock = FakeClock::new(Utc::now());
+        let result = retry_with_backoff(
+            &clock,
+            5,
+            Duration::from_secs(1),
+            Duration::from_secs(3),
+            |attempt| {
+                if attempt < 4 {
+                    Err(attempt)
+                } else {
+                    Ok(attempt)
+                }
+            },
+        );
+
+        assert_eq!(result, Ok(4));
+        assert_eq!(
+            *clock.sleeps.borrow(),
+            [1, 2, 3].map(Duration::from_secs).to_vec()
+        );
+    }
+
+    #[test]
+    fn returns_last_error_when_attempts_are_exhausted() {
+        let clock = FakeClock::new(Utc::now());
+        let result: Result<(), u32> = retry_with_backoff(
+            &clock,
+            3,
+            Duration::from_secs(1),
+            Duration::from_secs(60),
+            Err,
+        );
+
+        assert_eq!(result, Err(3));
+        assert_eq!(clock.sleeps.borrow().len(), 2);
+    }
+}
@@ -0,0 +1,74 @@
+use std::{
+    fs,
+    io::Write,
+    process::{self, Command, Stdio},
+};
+
+use crate::template::leaderboard::{render_day, render_rankings, Leaderboard, Order};
+use crate::template::{aoc_cli, Day};
+
+static LEADERBOARD_FILE_PATH: &str = "./data/leaderboard.json";
+
+/// Fetches the JSON export of a private leaderboard with the configured session cookie.
+fn fetch(id: u64) -> Result<String, String> {
+    let session = aoc_cli::session().ok_or(
+        "no session cookie configured. See the readme on how to configure aoc-cli integration.",
+    )?;
+    let year = aoc_cli::get_year().ok_or("`AOC_YEAR` is not set.")?;
+    let url = format!("https://adventofcode.com/{year}/leaderboard/private/view/{id}.json");
+
+    // The cookie is passed as a config file on stdin, so that it does not show up in the
+    // arguments of the process, which other users can read.
+    let mut child = Command::new("curl")
+        .args(["--silent", "--fail", "--location", "--config", "-"])
+        .arg(&url)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|_| "command \"curl\" not found or not callable.".to_string())?;
+    let config = format!("cookie = \"session={session}\"\n");
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(config.as_bytes())
+        .map_err(|e| e.to_string())?;
+    let output = child.wait_with_output().map_err(|e| e.to_string())?;
+
+    if !output.status.success() {
+        return Err(format!("could not fetch leaderboard from {url}."));
+    }
+
+    String::from_utf8(output.stdout).map_err(|e| e.to_string())
+}
+
+pub fn handle(file: Option<String>, id: Option<u64>, order: Order, day: Option<Day>) {
+    let json = match (file, id) {
+        (Some(path), _) => fs::read_to_string(&path).map_err(|e| format!("{path}: {e}")),
+        (None, Some(id)) => fetch(id).inspect(|json| {
+            // cache the export, so that subsequent calls work offline.
+            if let Err(e) = fs::write(LEADERBOARD_FILE_PATH, json) {
+                eprintln!("Failed to cache leaderboard: {e}");
+            }
+        }),
+        (None, None) => fs::read_to_string(LEADERBOARD_FILE_PATH).map_err(|_| {
+            format!(
+                "no cached leaderboard in \"{LEADERBOARD_FILE_PATH}\". \
+                Pass `--id <leaderboard id>` to fetch it, or `--file <path>` to load an export."
+            )
+        }),
+    };
+
+    let leaderboard = match json.and_then(Leaderboard::try_from) {
+        Ok(leaderboard) => leaderboard,
+        Err(e) => {
+            eprintln!("Failed to load leaderboard: {e}");
+            process::exit(1);
+        }
+    };
+
+    match day {
+        Some(day) => println!("{}", render_day(&leaderboard, day)),
+        None => println!("{}", render_rankings(&leaderboard, order)),
+    }
+}
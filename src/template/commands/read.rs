@@ -1,15 +1,82 @@
-use std::process;
+use std::{
+    env, fs,
+    io::{self, IsTerminal, Write},
+    process::{self, Command, Stdio},
+};
 
-use crate::template::{aoc_cli, Day};
+use terminal_size::Width;
 
-pub fn handle(day: Day) {
-    if aoc_cli::check().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        process::exit(1);
+use crate::template::{aoc_cli, markdown, Day};
+
+const DEFAULT_WIDTH: usize = 80;
+
+pub fn handle(day: Day, part_two: bool, pager: bool) {
+    let puzzle_path = aoc_cli::get_puzzle_path(day);
+
+    // the cached description is used when present, so reading works offline.
+    if fs::metadata(&puzzle_path).is_err() {
+        if aoc_cli::check().is_err() {
+            eprintln!("Could not find puzzle description \"{puzzle_path}\". Install aoc-cli (\"cargo install aoc-cli\") and run `cargo download {day}` to fetch it.");
+            process::exit(1);
+        }
+
+        if let Err(e) = aoc_cli::download_puzzle(day) {
+            eprintln!("failed to call aoc-cli: {e}");
+            process::exit(1);
+        }
     }
 
-    if let Err(e) = aoc_cli::read(day) {
-        eprintln!("failed to call aoc-cli: {e}");
-        process::exit(1);
+    let description = match fs::read_to_string(&puzzle_path) {
+        Ok(description) => description,
+        Err(e) => {
+            eprintln!("Failed to read puzzle description \"{puzzle_path}\": {e}");
+            process::exit(1);
+        }
     };
+
+    let description = if part_two {
+        markdown::part_two(&description).unwrap_or_else(|| {
+            eprintln!("Part 2 is not unlocked yet, showing the full description.");
+            &description
+        })
+    } else {
+        &description
+    };
+
+    let rendered = markdown::render(description, terminal_width());
+
+    if pager && io::stdout().is_terminal() && page(&rendered).is_ok() {
+        return;
+    }
+
+    println!("{rendered}");
+}
+
+/// Returns the width of the terminal, which `$COLUMNS` overrides.
+fn terminal_width() -> usize {
+    env::var("COLUMNS")
+        .ok()
+        .and_then(|c| c.parse().ok())
+        .or_else(|| terminal_size::terminal_size().map(|(Width(width), _)| width.into()))
+        .unwrap_or(DEFAULT_WIDTH)
+}
+
+/// Pipes `text` through `$PAGER`, defaulting to `less -R`.
+fn page(text: &str) -> io::Result<()> {
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less -R".into());
+    let mut parts = pager.split_whitespace();
+    let program = parts.next().ok_or(io::ErrorKind::NotFound)?;
+
+    let mut child = Command::new(program)
+        .args(parts)
+        .stdin(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        // the pager may quit before reading everything, which is not an error.
+        let _ = stdin.write_all(text.as_bytes());
+    }
+
+    child.wait()?;
+    Ok(())
 }
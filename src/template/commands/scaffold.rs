@@ -1,79 +1,629 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::{aoc_cli, Day, ANSI_BOLD, ANSI_RESET};
+
+const ANSI_RED: &str = "\x1b[31m";
+const ANSI_GREEN: &str = "\x1b[32m";
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
-fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
+/// The binary of a day, which only runs the solution in `src/days`.
+const BIN_TEMPLATE: &str = "use advent_of_code::days::day%DAY%::{part_one, part_two};
+
+advent_of_code::solution!(%DAY_NUMBER%);
+";
+
+/// The binary of day 25, which only has a single part.
+const LAST_BIN_TEMPLATE: &str = "use advent_of_code::days::day%DAY%::part_one;
+
+advent_of_code::solution!(%DAY_NUMBER%, 1);
+";
+
+static DAYS_MOD_PATH: &str = "src/days/mod.rs";
+
+/// Templates that ship with the template. A `templates/<name>.txt` file takes precedence over these.
+const BUILTIN_TEMPLATES: [(&str, &str); 5] = [
+    ("default", MODULE_TEMPLATE),
+    (
+        "grid",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/template/variants/grid.txt"
+        )),
+    ),
+    (
+        "graph",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/template/variants/graph.txt"
+        )),
+    ),
+    (
+        "parser",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/template/variants/parser.txt"
+        )),
+    ),
+    (
+        "vm",
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/src/template/variants/vm.txt"
+        )),
+    ),
+];
+
+static TEMPLATES_DIR: &str = "templates";
+
+pub struct Options {
+    /// Name of the template to scaffold the module from.
+    pub template: String,
+    /// Replace an existing module, unless it contains a solution.
+    pub overwrite: bool,
+    /// Replace an existing module, even if it contains a solution.
+    pub force: bool,
+    /// Print what would be created instead of creating it.
+    pub dry_run: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            template: "default".into(),
+            overwrite: false,
+            force: false,
+            dry_run: false,
+        }
+    }
+}
+
+/// Values that are substituted for the placeholders of a template.
+struct Placeholders {
+    day: Day,
+    year: Option<u16>,
+    title: String,
+}
+
+impl Placeholders {
+    fn new(day: Day) -> Self {
+        let title = fs::read_to_string(aoc_cli::get_puzzle_path(day))
+            .ok()
+            .and_then(|puzzle| scrape_title(&puzzle))
+            .unwrap_or_else(|| "Untitled".into());
+
+        Self {
+            day,
+            year: aoc_cli::get_year(),
+            title,
+        }
+    }
+
+    fn render(&self, template: &str) -> String {
+        let year = self.year.map(|y| y.to_string()).unwrap_or_default();
+        let date = match self.year {
+            Some(year) => format!("{year}-12-{}", self.day),
+            None => format!("12-{}", self.day),
+        };
+
+        template
+            .replace("%DAY%", &self.day.to_string())
+            .replace("%DAY_NUMBER%", &self.day.into_inner().to_string())
+            .replace("%YEAR%", &year)
+            .replace("%TITLE%", &self.title)
+            .replace("%DATE%", &date)
+    }
+}
+
+/// Extracts the puzzle title from a line like `--- Day 1: Not Quite Lisp ---`.
+fn scrape_title(puzzle: &str) -> Option<String> {
+    let heading = puzzle.lines().find(|l| l.contains("--- Day "))?;
+    let (_, title) = heading.split_once(": ")?;
+    let title = title.trim().trim_end_matches('-').trim();
+    (!title.is_empty()).then(|| title.to_string())
+}
+
+/// Returns the names of all built-in templates and templates in the `templates` folder.
+fn available_templates() -> Vec<String> {
+    let mut names: Vec<String> = BUILTIN_TEMPLATES
+        .iter()
+        .map(|(name, _)| name.to_string())
+        .collect();
+
+    if let Ok(entries) = fs::read_dir(TEMPLATES_DIR) {
+        names.extend(entries.filter_map(|entry| {
+            let path = entry.ok()?.path();
+            if path.extension()? != "txt" {
+                return None;
+            }
+            Some(path.file_stem()?.to_string_lossy().to_string())
+        }));
+    }
+
+    names.sort_unstable();
+    names.dedup();
+    names
+}
+
+/// Loads a template by name, preferring a user-provided file over the built-in template.
+/// Returns the template together with a description of where it was loaded from.
+fn load_template(name: &str) -> Result<(String, String), String> {
+    let path = Path::new(TEMPLATES_DIR).join(format!("{name}.txt"));
+    if let Ok(contents) = fs::read_to_string(&path) {
+        return Ok((contents, format!("\"{}\"", path.display())));
+    }
+
+    BUILTIN_TEMPLATES
+        .iter()
+        .find(|(builtin, _)| *builtin == name)
+        .map(|(_, contents)| (contents.to_string(), "built-in".into()))
+        .ok_or_else(|| {
+            format!(
+                "unknown template `{name}`. Available templates: {}.",
+                available_templates().join(", ")
+            )
+        })
+}
+
+/// Checks whether a module is still the unmodified output of one of the available templates.
+/// The title and date may have changed since the module was scaffolded, so any text is accepted
+/// in their place.
+fn is_pristine(module: &str, placeholders: &Placeholders) -> bool {
+    available_templates().iter().any(|name| {
+        load_template(name).is_ok_and(|(template, _)| {
+            template.lines().count() == module.lines().count()
+                && template.ends_with('\n') == module.ends_with('\n')
+                && template
+                    .lines()
+                    .zip(module.lines())
+                    .all(|(t, m)| matches_loosely(t, m, placeholders))
+        })
+    })
+}
+
+/// Checks whether `line` is `template` rendered with any title and date.
+fn matches_loosely(template: &str, line: &str, placeholders: &Placeholders) -> bool {
+    let template = template.replace("%TITLE%", "%DATE%");
+    let parts: Vec<_> = template
+        .split("%DATE%")
+        .map(|part| placeholders.render(part))
+        .collect();
+    let [first, middle @ .., last] = parts.as_slice() else {
+        return placeholders.render(&template) == line;
+    };
+    let Some(mut rest) = line.strip_prefix(first.as_str()) else {
+        return false;
+    };
+    for part in middle {
+        match rest.find(part.as_str()) {
+            Some(index) => rest = &rest[index + part.len()..],
+            None => return false,
+        }
+    }
+    rest.ends_with(last.as_str())
+}
+
+/// A line of a diff between two texts.
+#[derive(Debug, PartialEq, Eq)]
+enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Computes a line-based diff between two texts via their longest common subsequence.
+fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<_> = old.lines().collect();
+    let new: Vec<_> = new.lines().collect();
+
+    // lcs[i][j] holds the length of the longest common subsequence of old[i..] and new[j..].
+    let mut lcs = vec![vec![0_usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let (mut i, mut j) = (0, 0);
+    let mut lines = vec![];
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            lines.push(DiffLine::Same(old[i]));
+            i += 1;
+            j += 1;
+        } else if j < new.len() && (i == old.len() || lcs[i][j + 1] >= lcs[i + 1][j]) {
+            lines.push(DiffLine::Added(new[j]));
+            j += 1;
+        } else {
+            lines.push(DiffLine::Removed(old[i]));
+            i += 1;
+        }
+    }
+    lines
+}
+
+/// Renders the changed lines of a diff, with up to two lines of context around each change.
+fn render_diff(lines: &[DiffLine]) -> String {
+    const CONTEXT: usize = 2;
+
+    let is_change = |i: usize| !matches!(lines.get(i), Some(DiffLine::Same(_)) | None);
+    let mut out = vec![];
+    let mut skipped = false;
+
+    for (i, line) in lines.iter().enumerate() {
+        let near_change = (i.saturating_sub(CONTEXT)..=i + CONTEXT).any(is_change);
+        match line {
+            DiffLine::Same(_) if !near_change => {
+                skipped = true;
+                continue;
+            }
+            _ if skipped => {
+                out.push(format!("{ANSI_BOLD}@@{ANSI_RESET}"));
+                skipped = false;
+            }
+            _ => {}
+        }
+
+        out.push(match line {
+            DiffLine::Same(l) => format!("  {l}"),
+            DiffLine::Removed(l) => format!("{ANSI_RED}- {l}{ANSI_RESET}"),
+            DiffLine::Added(l) => format!("{ANSI_GREEN}+ {l}{ANSI_RESET}"),
+        });
+    }
+
+    out.join("\n")
+}
+
+/// Creates an empty file, unless it already exists. Returns whether the file was created.
+fn create_missing_file(path: &str, dry_run: bool) -> Result<bool, std::io::Error> {
+    if Path::new(path).exists() {
+        return Ok(false);
+    }
+    if !dry_run {
+        OpenOptions::new().write(true).create_new(true).open(path)?;
+    }
+    Ok(true)
+}
+
+/// Adds `pub mod <module>;` to the module declarations of `src/days/mod.rs`, keeping them sorted.
+/// Returns `None` if the module is already declared.
+fn register_module(days_mod: &str, module: &str) -> Option<String> {
+    let declaration = format!("pub mod {module};");
+    let mut lines: Vec<&str> = days_mod.lines().collect();
+    if lines.contains(&declaration.as_str()) {
+        return None;
+    }
+
+    let position = lines
+        .iter()
+        .rposition(|line| line.starts_with("pub mod ") && *line < declaration.as_str())
+        .map(|i| i + 1)
+        .or_else(|| lines.iter().position(|line| line.starts_with("pub mod ")))
+        .unwrap_or(lines.len());
+    lines.insert(position, &declaration);
+
+    let mut contents = lines.join("\n");
+    contents.push('\n');
+    Some(contents)
+}
+
+fn write_module(path: &str, module: &str, replace: bool) -> Result<(), std::io::Error> {
     let mut file = OpenOptions::new();
-    if overwrite {
+    if replace {
         file.create(true);
     } else {
         file.create_new(true);
     }
-    file.truncate(true).write(true).open(path)
+    file.truncate(true)
+        .write(true)
+        .open(path)?
+        .write_all(module.as_bytes())
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new()
-        .write(true)
-        .create(true)
-        .truncate(true)
-        .open(path)
+/// Calls `download` before scaffolding the day, so that the module is rendered with the title of
+/// the downloaded puzzle. Dry runs skip the download.
+pub fn handle_with_download(day: Day, options: &Options, download: impl FnOnce(Day)) {
+    if !options.dry_run {
+        download(day);
+    }
+    handle(day, options);
 }
 
-pub fn handle(day: Day, overwrite: bool) {
+pub fn handle(day: Day, options: &Options) {
     let input_path = format!("data/inputs/{day}.txt");
     let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+    let module_name = format!("day{day}");
+    let module_path = format!("src/days/{module_name}.rs");
+    let bin_path = format!("src/bin/{day}.rs");
+    let backup_path = format!("{module_path}.bak");
 
-    let mut file = match safe_create_file(&module_path, overwrite) {
-        Ok(file) => file,
+    let (template, source) = match load_template(&options.template) {
+        Ok(template) => template,
         Err(e) => {
-            eprintln!("Failed to create module file: {e}");
+            eprintln!("Failed to load template: {e}");
             process::exit(1);
         }
     };
 
-    match file.write_all(
-        MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
-            .as_bytes(),
-    ) {
-        Ok(()) => {
-            println!("Created module file \"{}\"", &module_path);
+    let placeholders = Placeholders::new(day);
+    let module = placeholders.render(&template);
+
+    let prefix = if options.dry_run { "Would " } else { "" };
+    let mut skipped = vec![];
+
+    match fs::read_to_string(&module_path).ok() {
+        None => {
+            if options.dry_run {
+                println!(
+                    "Would create module file \"{}\" from {} template `{}`:",
+                    &module_path, source, options.template
+                );
+                println!("---");
+                print!("{module}");
+                println!("---");
+            } else if let Err(e) = write_module(&module_path, &module, false) {
+                eprintln!("Failed to create module file: {e}");
+                process::exit(1);
+            } else {
+                println!("Created module file \"{}\"", &module_path);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to write module contents: {e}");
-            process::exit(1);
+        Some(existing) if existing == module => {
+            skipped.push(format!("module file \"{module_path}\" (up to date)"));
+        }
+        Some(_) if !options.overwrite && !options.force => {
+            skipped.push(format!(
+                "module file \"{module_path}\" (already exists, use `--overwrite` to replace it)"
+            ));
+        }
+        Some(existing) if !options.force && !is_pristine(&existing, &placeholders) => {
+            skipped.push(format!(
+                "module file \"{module_path}\" (contains a solution, use `--force` to replace it)"
+            ));
+        }
+        Some(existing) => {
+            println!(
+                "{prefix}{} module file \"{}\" from {} template `{}`:",
+                if options.dry_run {
+                    "replace"
+                } else {
+                    "Replaced"
+                },
+                &module_path,
+                source,
+                options.template
+            );
+            println!("{}", render_diff(&diff_lines(&existing, &module)));
+
+            if !options.dry_run {
+                if let Err(e) = fs::write(&backup_path, &existing) {
+                    eprintln!("Failed to back up module file: {e}");
+                    process::exit(1);
+                }
+                if let Err(e) = write_module(&module_path, &module, true) {
+                    eprintln!("Failed to write module contents: {e}");
+                    process::exit(1);
+                }
+            }
+            println!(
+                "{prefix}{} the previous module to \"{}\"",
+                if options.dry_run {
+                    "back up"
+                } else {
+                    "Backed up"
+                },
+                &backup_path
+            );
         }
     }
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    match fs::read_to_string(DAYS_MOD_PATH).map(|m| register_module(&m, &module_name)) {
+        Ok(Some(contents)) => {
+            if !options.dry_run {
+                if let Err(e) = fs::write(DAYS_MOD_PATH, contents) {
+                    eprintln!("Failed to register module: {e}");
+                    process::exit(1);
+                }
+            }
+            println!(
+                "{prefix}{} `pub mod {module_name};` in \"{DAYS_MOD_PATH}\"",
+                if options.dry_run {
+                    "declare"
+                } else {
+                    "Declared"
+                }
+            );
         }
+        Ok(None) => skipped.push(format!(
+            "module declaration (already in \"{DAYS_MOD_PATH}\")"
+        )),
         Err(e) => {
-            eprintln!("Failed to create input file: {e}");
+            eprintln!("Failed to read \"{DAYS_MOD_PATH}\": {e}");
             process::exit(1);
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    // the binary holds no code of its own, so an existing one is kept as is.
+    if Path::new(&bin_path).exists() {
+        skipped.push(format!("binary file \"{bin_path}\" (already exists)"));
+    } else {
+        if !options.dry_run {
+            let template = if day.has_part_two() {
+                BIN_TEMPLATE
+            } else {
+                LAST_BIN_TEMPLATE
+            };
+            if let Err(e) = write_module(&bin_path, &placeholders.render(template), false) {
+                eprintln!("Failed to create binary file: {e}");
+                process::exit(1);
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {e}");
-            process::exit(1);
+        println!(
+            "{prefix}{} binary file \"{bin_path}\"",
+            if options.dry_run { "create" } else { "Created" }
+        );
+    }
+
+    // inputs and examples are never replaced: they may hold a downloaded input or a curated example.
+    for (kind, path) in [("input", &input_path), ("example", &example_path)] {
+        match create_missing_file(path, options.dry_run) {
+            Ok(true) => println!(
+                "{prefix}{} empty {kind} file \"{path}\"",
+                if options.dry_run { "create" } else { "Created" }
+            ),
+            Ok(false) => skipped.push(format!("{kind} file \"{path}\" (already exists)")),
+            Err(e) => {
+                eprintln!("Failed to create {kind} file: {e}");
+                process::exit(1);
+            }
         }
     }
 
-    println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    for reason in &skipped {
+        println!("Skipped {reason}.");
+    }
+
+    if !options.dry_run {
+        println!("---");
+        println!("🎄 Type `cargo solve {day}` to run your solution.");
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        diff_lines, is_pristine, load_template, register_module, render_diff, scrape_title,
+        DiffLine, Placeholders, BIN_TEMPLATE, MODULE_TEMPLATE,
+    };
+    use crate::day;
+
+    fn get_mock_placeholders() -> Placeholders {
+        Placeholders {
+            day: day!(7),
+            year: Some(2015),
+            title: "Some Assembly Required".into(),
+        }
+    }
+
+    #[test]
+    fn renders_placeholders() {
+        let rendered = get_mock_placeholders().render("%DAY_NUMBER% %YEAR% %TITLE% %DATE%");
+        assert_eq!(rendered, "7 2015 Some Assembly Required 2015-12-07");
+    }
+
+    #[test]
+    fn scrapes_title() {
+        let puzzle = "\\--- Day 7: Some Assembly Required ---\n----------\n\nThis year...";
+        assert_eq!(
+            scrape_title(puzzle),
+            Some("Some Assembly Required".to_string())
+        );
+        assert_eq!(scrape_title("no heading"), None);
+    }
+
+    #[test]
+    fn loads_builtin_templates() {
+        for name in ["default", "grid", "graph", "parser", "vm"] {
+            let (template, source) = load_template(name).unwrap();
+            assert!(template.contains("%DAY_NUMBER%"));
+            assert_eq!(source, "built-in");
+        }
+        assert!(load_template("unknown").is_err());
+    }
+
+    #[test]
+    fn detects_pristine_modules() {
+        let placeholders = get_mock_placeholders();
+        let module = placeholders.render(MODULE_TEMPLATE);
+        assert!(is_pristine(&module, &placeholders));
+
+        let untitled = Placeholders {
+            title: "Untitled".into(),
+            year: None,
+            ..get_mock_placeholders()
+        };
+        assert!(is_pristine(
+            &untitled.render(MODULE_TEMPLATE),
+            &placeholders
+        ));
+        assert!(!is_pristine(
+            &module.replace("//! Day 7", "//! Day 8"),
+            &placeholders
+        ));
+        assert!(!is_pristine(
+            &module.replace("None", "Some(1)"),
+            &placeholders
+        ));
+    }
+
+    #[test]
+    fn renders_bin_template() {
+        let rendered = get_mock_placeholders().render(BIN_TEMPLATE);
+        assert!(rendered.starts_with("use advent_of_code::days::day07::{part_one, part_two};"));
+        assert!(rendered.contains("advent_of_code::solution!(7);"));
+    }
+
+    #[test]
+    fn registers_modules_in_order() {
+        let days_mod = "//! Days.\n\npub mod day01;\npub mod day03;\n";
+        assert_eq!(
+            register_module(days_mod, "day02").as_deref(),
+            Some("//! Days.\n\npub mod day01;\npub mod day02;\npub mod day03;\n")
+        );
+        assert_eq!(
+            register_module(days_mod, "day04").as_deref(),
+            Some("//! Days.\n\npub mod day01;\npub mod day03;\npub mod day04;\n")
+        );
+        assert_eq!(
+            register_module(days_mod, "day00").as_deref(),
+            Some("//! Days.\n\npub mod day00;\npub mod day01;\npub mod day03;\n")
+        );
+        assert_eq!(register_module(days_mod, "day03"), None);
+        assert_eq!(
+            register_module("//! Days.\n", "day01").as_deref(),
+            Some("//! Days.\npub mod day01;\n")
+        );
+    }
+
+    #[test]
+    fn diffs_lines() {
+        let diff = diff_lines("a\nb\nc", "a\nx\nc\nd");
+        assert_eq!(
+            diff,
+            vec![
+                DiffLine::Same("a"),
+                DiffLine::Added("x"),
+                DiffLine::Removed("b"),
+                DiffLine::Same("c"),
+                DiffLine::Added("d"),
+            ]
+        );
+    }
+
+    #[test]
+    fn renders_diff_with_context() {
+        let old = (1..=10)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join("\n");
+        let new = old.replace("9", "nine");
+        let rendered = render_diff(&diff_lines(&old, &new));
+        let lines: Vec<_> = rendered.lines().collect();
+
+        assert!(lines[0].contains("@@"));
+        assert_eq!(lines[1], "  7");
+        assert!(lines.iter().any(|l| l.contains("- 9")));
+        assert!(lines.iter().any(|l| l.contains("+ nine")));
+        assert_eq!(lines.last(), Some(&"  10"));
+    }
 }
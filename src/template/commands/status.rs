@@ -0,0 +1,19 @@
+use crate::template::timings::Timings;
+use crate::template::{readme_stars, status};
+
+pub fn handle(store: bool) {
+    let statuses = status::collect(&Timings::read_from_file());
+    println!("{}", status::render(&statuses));
+
+    if store {
+        println!();
+        match readme_stars::update(&statuses) {
+            Ok(()) => {
+                println!("Stored updated stars summary.");
+            }
+            Err(_) => {
+                eprintln!("Failed to store updated stars summary.");
+            }
+        }
+    }
+}
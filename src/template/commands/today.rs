@@ -0,0 +1,146 @@
+use std::{io::Write, process, time::Duration};
+
+use chrono::{DateTime, Utc};
+
+use crate::template::clock::{retry_with_backoff, Clock, SystemClock};
+use crate::template::commands::{read, scaffold};
+use crate::template::{aoc_cli, Day, ANSI_BOLD, ANSI_RESET};
+
+const DOWNLOAD_ATTEMPTS: u32 = 8;
+const DOWNLOAD_INITIAL_DELAY: Duration = Duration::from_secs(2);
+const DOWNLOAD_MAX_DELAY: Duration = Duration::from_secs(60);
+
+pub fn handle(wait: bool) {
+    handle_with_clock(&SystemClock, wait);
+}
+
+pub fn handle_with_clock(clock: &impl Clock, wait: bool) {
+    if !wait {
+        let Some(day) = Day::today_at(clock.now()) else {
+            eprintln!(
+                "`today` command can only be run between the 1st and \
+                the 25th of december. Please use `scaffold` with a specific day, \
+                or pass `--wait` to wait for the next puzzle to unlock."
+            );
+            process::exit(1);
+        };
+
+        scaffold::handle_with_download(day, &scaffold::Options::default(), |day| fetch(clock, day));
+        read::handle(day, false, true);
+        return;
+    }
+
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    let Some((day, unlock)) = Day::next_unlock(clock.now()) else {
+        eprintln!("Could not determine the next puzzle unlock.");
+        process::exit(1);
+    };
+
+    println!("🎄 Day {day} unlocks at {unlock} (midnight EST).");
+    wait_until(clock, unlock, |remaining| {
+        print!(
+            "\r⏳ Day {day} unlocks in {ANSI_BOLD}{}{ANSI_RESET} ",
+            format_countdown(remaining)
+        );
+        let _ = std::io::stdout().flush();
+    });
+    println!("\r🎄 Day {day} is unlocked!                ");
+
+    scaffold::handle_with_download(day, &scaffold::Options::default(), |day| fetch(clock, day));
+    read::handle(day, false, true);
+}
+
+/// Downloads the input and puzzle for a day, retrying while the input is not available yet.
+fn fetch(clock: &impl Clock, day: Day) {
+    if aoc_cli::check().is_err() {
+        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
+        process::exit(1);
+    }
+
+    let result = retry_with_backoff(
+        clock,
+        DOWNLOAD_ATTEMPTS,
+        DOWNLOAD_INITIAL_DELAY,
+        DOWNLOAD_MAX_DELAY,
+        |attempt| {
+            if attempt > 1 {
+                println!("Retrying download (attempt {attempt}/{DOWNLOAD_ATTEMPTS})...");
+            }
+            aoc_cli::download(day)
+        },
+    );
+
+    if let Err(e) = result {
+        eprintln!("failed to call aoc-cli: {e}");
+        process::exit(1);
+    }
+}
+
+/// Sleeps until `deadline`, calling `tick` with the remaining time about once per second.
+pub fn wait_until(clock: &impl Clock, deadline: DateTime<Utc>, mut tick: impl FnMut(Duration)) {
+    while let Ok(remaining) = (deadline - clock.now()).to_std() {
+        if remaining.is_zero() {
+            break;
+        }
+
+        tick(remaining);
+        clock.sleep(remaining.min(Duration::from_secs(1)));
+    }
+}
+
+fn format_countdown(remaining: Duration) -> String {
+    let secs = remaining.as_secs() + u64::from(remaining.subsec_nanos() > 0);
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    let (hours, minutes, secs) = (secs / 3600, secs % 3600 / 60, secs % 60);
+
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{secs:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{secs:02}")
+    }
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_countdown, wait_until};
+    use crate::template::clock::tests::FakeClock;
+    use chrono::{TimeZone, Utc};
+    use std::time::Duration;
+
+    #[test]
+    fn waits_until_deadline() {
+        let clock = FakeClock::new(Utc.with_ymd_and_hms(2023, 12, 7, 4, 59, 57).unwrap());
+        let deadline = Utc.with_ymd_and_hms(2023, 12, 7, 5, 0, 0).unwrap();
+
+        let mut ticks = vec![];
+        wait_until(&clock, deadline, |remaining| {
+            ticks.push(remaining.as_secs())
+        });
+
+        assert_eq!(ticks, vec![3, 2, 1]);
+        assert_eq!(*clock.now.borrow(), deadline);
+    }
+
+    #[test]
+    fn does_not_wait_for_past_deadlines() {
+        let clock = FakeClock::new(Utc.with_ymd_and_hms(2023, 12, 7, 5, 0, 1).unwrap());
+        let deadline = Utc.with_ymd_and_hms(2023, 12, 7, 5, 0, 0).unwrap();
+
+        wait_until(&clock, deadline, |_| panic!("should not tick"));
+        assert!(clock.sleeps.borrow().is_empty());
+    }
+
+    #[test]
+    fn formats_countdown() {
+        assert_eq!(format_countdown(Duration::from_secs(59)), "00:00:59");
+        assert_eq!(
+            format_countdown(Duration::from_millis(3_600_500)),
+            "01:00:01"
+        );
+        assert_eq!(format_countdown(Duration::from_secs(90_061)), "1d 01:01:01");
+    }
+}
@@ -3,7 +3,7 @@ use std::fmt::Display;
 use std::str::FromStr;
 
 #[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, TimeZone, Utc};
 
 #[cfg(feature = "today")]
 const SERVER_UTC_OFFSET: i32 = -5;
@@ -41,20 +41,53 @@ impl Day {
     pub fn into_inner(self) -> u8 {
         self.0
     }
+
+    /// Returns whether the day has a second puzzle. The second star of day 25 is awarded for
+    /// completing all other puzzles instead.
+    pub fn has_part_two(self) -> bool {
+        self.0 != 25
+    }
 }
 
 #[cfg(feature = "today")]
 impl Day {
     /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
     pub fn today() -> Option<Self> {
+        Self::today_at(Utc::now())
+    }
+
+    /// Returns the day of advent at the point in time `now`, or `None` if it is not between
+    /// the 1st and the 25th of december in the server's timezone.
+    pub fn today_at(now: DateTime<Utc>) -> Option<Self> {
         let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
+        let today = now.with_timezone(&offset);
         if today.month() == 12 && today.day() <= 25 {
             Self::new(u8::try_from(today.day()).ok()?)
         } else {
             None
         }
     }
+
+    /// Returns the next day to unlock after the point in time `now`, together with the time
+    /// it unlocks at. Puzzles unlock at midnight in the server's timezone.
+    pub fn next_unlock(now: DateTime<Utc>) -> Option<(Self, DateTime<Utc>)> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = now.with_timezone(&offset);
+
+        let (year, day) = match (today.month(), today.day()) {
+            (12, day) if day < 25 => (today.year(), day + 1),
+            (12, _) => (today.year() + 1, 1),
+            _ => (today.year(), 1),
+        };
+
+        let unlock = NaiveDate::from_ymd_opt(year, 12, day)?.and_hms_opt(0, 0, 0)?;
+        let unlock = offset.from_local_datetime(&unlock).single()?;
+
+        Some((
+            Self::new(u8::try_from(day).ok()?)?,
+            unlock.with_timezone(&Utc),
+        ))
+    }
 }
 
 impl Display for Day {
@@ -187,6 +220,48 @@ mod tests {
         assert_eq!(iter.next(), Some(Day(25)));
         assert_eq!(iter.next(), None);
     }
+
+    #[cfg(feature = "today")]
+    mod unlock {
+        use super::Day;
+        use chrono::{TimeZone, Utc};
+
+        #[test]
+        fn today_uses_server_timezone() {
+            let before_midnight = Utc.with_ymd_and_hms(2023, 12, 2, 4, 59, 59).unwrap();
+            let after_midnight = Utc.with_ymd_and_hms(2023, 12, 2, 5, 0, 0).unwrap();
+            assert_eq!(Day::today_at(before_midnight), Some(Day(1)));
+            assert_eq!(Day::today_at(after_midnight), Some(Day(2)));
+            assert_eq!(
+                Day::today_at(Utc.with_ymd_and_hms(2023, 11, 30, 12, 0, 0).unwrap()),
+                None
+            );
+        }
+
+        #[test]
+        fn next_unlock_during_advent() {
+            let now = Utc.with_ymd_and_hms(2023, 12, 7, 18, 30, 0).unwrap();
+            let (day, unlock) = Day::next_unlock(now).unwrap();
+            assert_eq!(day, Day(8));
+            assert_eq!(unlock, Utc.with_ymd_and_hms(2023, 12, 8, 5, 0, 0).unwrap());
+        }
+
+        #[test]
+        fn next_unlock_before_advent() {
+            let now = Utc.with_ymd_and_hms(2023, 11, 30, 23, 0, 0).unwrap();
+            let (day, unlock) = Day::next_unlock(now).unwrap();
+            assert_eq!(day, Day(1));
+            assert_eq!(unlock, Utc.with_ymd_and_hms(2023, 12, 1, 5, 0, 0).unwrap());
+        }
+
+        #[test]
+        fn next_unlock_after_advent() {
+            let now = Utc.with_ymd_and_hms(2023, 12, 26, 5, 0, 0).unwrap();
+            let (day, unlock) = Day::next_unlock(now).unwrap();
+            assert_eq!(day, Day(1));
+            assert_eq!(unlock, Utc.with_ymd_and_hms(2024, 12, 1, 5, 0, 0).unwrap());
+        }
+    }
 }
 
 /* -------------------------------------------------------------------------- */
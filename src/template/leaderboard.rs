@@ -0,0 +1,439 @@
+/// Module that parses and renders private leaderboards, as exported by the Advent of Code website.
+use std::{collections::HashMap, str::FromStr};
+use tinyjson::JsonValue;
+
+use crate::template::{all_days, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_GOLD: &str = "\x1b[93m";
+const ANSI_SILVER: &str = "\x1b[37m";
+const ANSI_DIM: &str = "\x1b[2m";
+
+/// Puzzles unlock at midnight EST, i.e. 05:00 UTC.
+const UNLOCK_UTC_HOUR: i64 = 5;
+
+/// A member of a private leaderboard.
+#[derive(Clone, Debug)]
+pub struct Member {
+    pub id: u64,
+    pub name: Option<String>,
+    pub local_score: u64,
+    pub global_score: u64,
+    pub stars: u64,
+    pub last_star_ts: u64,
+    /// Unix timestamps of the stars collected per day and part.
+    pub completions: [[Option<u64>; 2]; 25],
+}
+
+impl Member {
+    #[must_use]
+    pub fn display_name(&self) -> String {
+        self.name
+            .clone()
+            .unwrap_or_else(|| format!("(anonymous user #{})", self.id))
+    }
+
+    fn completion(&self, day: Day, part: usize) -> Option<u64> {
+        self.completions[usize::from(day.into_inner()) - 1][part]
+    }
+
+    /// Seconds between solving part 1 and part 2 of a day. `None` for a malformed export in
+    /// which part 2 was solved before part 1.
+    fn delta(&self, day: Day) -> Option<u64> {
+        self.completion(day, 1)?
+            .checked_sub(self.completion(day, 0)?)
+    }
+
+    fn median_delta(&self) -> Option<u64> {
+        let mut deltas: Vec<_> = all_days().filter_map(|day| self.delta(day)).collect();
+        deltas.sort_unstable();
+        deltas.get(deltas.len() / 2).copied()
+    }
+}
+
+/// A private leaderboard for one event.
+#[derive(Clone, Debug)]
+pub struct Leaderboard {
+    pub year: i64,
+    pub members: Vec<Member>,
+}
+
+/// Criteria a leaderboard can be ranked by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Order {
+    Local,
+    Global,
+    Stars,
+}
+
+impl FromStr for Order {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "local" => Ok(Order::Local),
+            "global" => Ok(Order::Global),
+            "stars" => Ok(Order::Stars),
+            _ => Err(format!(
+                "unknown order `{s}`, expecting `local`, `global` or `stars`"
+            )),
+        }
+    }
+}
+
+impl Leaderboard {
+    /// Returns the members sorted by `order`, breaking ties by who got their last star first.
+    #[must_use]
+    pub fn ranked(&self, order: Order) -> Vec<&Member> {
+        let mut members: Vec<_> = self.members.iter().collect();
+        members.sort_by(|a, b| {
+            let key = |m: &Member| match order {
+                Order::Local => m.local_score,
+                Order::Global => m.global_score,
+                Order::Stars => m.stars,
+            };
+            key(b)
+                .cmp(&key(a))
+                .then(a.last_star_ts.cmp(&b.last_star_ts))
+        });
+        members
+    }
+
+    /// Returns the unix timestamp a day of this event unlocks at.
+    #[must_use]
+    pub fn unlock_ts(&self, day: Day) -> i64 {
+        let days = days_from_civil(self.year, 12, i64::from(day.into_inner()));
+        days * 86_400 + UNLOCK_UTC_HOUR * 3600
+    }
+
+    /// Seconds from the unlock of a day until a member solved one of its parts.
+    fn solve_time(&self, member: &Member, day: Day, part: usize) -> Option<u64> {
+        let ts = i64::try_from(member.completion(day, part)?).ok()?;
+        u64::try_from(ts - self.unlock_ts(day)).ok()
+    }
+}
+
+/// Days since the unix epoch for a date of the proleptic gregorian calendar.
+/// See: <https://howardhinnant.github.io/date_algorithms.html#days_from_civil>
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let yoe = year - era * 400;
+    let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+fn format_duration(secs: u64) -> String {
+    let (days, secs) = (secs / 86_400, secs % 86_400);
+    let (hours, minutes, secs) = (secs / 3600, secs % 3600 / 60, secs % 60);
+    if days > 0 {
+        format!("{days}d {hours:02}:{minutes:02}:{secs:02}")
+    } else {
+        format!("{hours:02}:{minutes:02}:{secs:02}")
+    }
+}
+
+fn format_optional_duration(secs: Option<u64>) -> String {
+    secs.map_or_else(|| "-".into(), format_duration)
+}
+
+fn star_strip(member: &Member) -> String {
+    all_days()
+        .map(|day| {
+            match (
+                member.completion(day, 0).is_some(),
+                member.completion(day, 1).is_some(),
+            ) {
+                (true, true) => format!("{ANSI_GOLD}*{ANSI_RESET}"),
+                (true, false) => format!("{ANSI_SILVER}*{ANSI_RESET}"),
+                _ => format!("{ANSI_DIM}.{ANSI_RESET}"),
+            }
+        })
+        .collect()
+}
+
+/// Renders the rankings of all members, with a star per solved day and their median time from
+/// part 1 to part 2.
+#[must_use]
+pub fn render_rankings(leaderboard: &Leaderboard, order: Order) -> String {
+    let name_width = leaderboard
+        .members
+        .iter()
+        .map(|m| m.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    let mut lines = vec![format!(
+        "{ANSI_BOLD}{:>4}  {:<name_width$}  {:>5}  {:>6}  {:>5}  {:<25}  {:>11}{ANSI_RESET}",
+        "#", "Name", "Local", "Global", "Stars", "Days 1-25", "Median Δ"
+    )];
+
+    for (rank, member) in leaderboard.ranked(order).into_iter().enumerate() {
+        lines.push(format!(
+            "{:>3})  {:<name_width$}  {:>5}  {:>6}  {:>5}  {}  {:>11}",
+            rank + 1,
+            member.display_name(),
+            member.local_score,
+            member.global_score,
+            member.stars,
+            star_strip(member),
+            format_optional_duration(member.median_delta()),
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/// Renders the completion times of all members for a single day, ordered by part 2 times.
+#[must_use]
+pub fn render_day(leaderboard: &Leaderboard, day: Day) -> String {
+    let mut members: Vec<_> = leaderboard
+        .members
+        .iter()
+        .filter(|m| m.completion(day, 0).is_some())
+        .collect();
+
+    members.sort_by_key(|m| {
+        (
+            m.completion(day, 1).unwrap_or(u64::MAX),
+            m.completion(day, 0),
+        )
+    });
+
+    let name_width = members
+        .iter()
+        .map(|m| m.display_name().chars().count())
+        .max()
+        .unwrap_or(0)
+        .max(4);
+
+    let mut lines = vec![
+        format!("{ANSI_BOLD}Day {day}{ANSI_RESET}"),
+        String::new(),
+        format!(
+            "{ANSI_BOLD}{:>4}  {:<name_width$}  {:>11}  {:>11}  {:>11}{ANSI_RESET}",
+            "#", "Name", "Part 1", "Part 2", "Δ"
+        ),
+    ];
+
+    if members.is_empty() {
+        lines.push(format!(
+            "{ANSI_ITALIC}Nobody has solved this day yet.{ANSI_RESET}"
+        ));
+    }
+
+    for (rank, member) in members.into_iter().enumerate() {
+        lines.push(format!(
+            "{:>3})  {:<name_width$}  {:>11}  {:>11}  {:>11}",
+            rank + 1,
+            member.display_name(),
+            format_optional_duration(leaderboard.solve_time(member, day, 0)),
+            format_optional_duration(leaderboard.solve_time(member, day, 1)),
+            format_optional_duration(member.delta(day)),
+        ));
+    }
+
+    lines.join("\n")
+}
+
+/* -------------------------------------------------------------------------- */
+
+fn get_u64(json: &HashMap<String, JsonValue>, key: &str) -> Option<u64> {
+    json.get(key)
+        .and_then(|v| v.get::<f64>())
+        .map(|n| *n as u64)
+}
+
+impl TryFrom<&JsonValue> for Member {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected member to be a JSON object.")?;
+
+        let id = get_u64(json, "id").ok_or("Expected member.id to be a number.")?;
+
+        let mut completions = [[None; 2]; 25];
+        if let Some(days) = json
+            .get("completion_day_level")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+        {
+            for (day, parts) in days {
+                let day = Day::from_str(day)
+                    .map_err(|_| format!("Expected {day} in completion_day_level to be a day."))?;
+                let parts = parts
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected completion_day_level entries to be objects.")?;
+
+                for (part, star) in parts {
+                    let part = match part.as_str() {
+                        "1" => 0,
+                        "2" => 1,
+                        _ => return Err(format!("Unexpected part `{part}` in completion.")),
+                    };
+                    let ts = star
+                        .get::<HashMap<String, JsonValue>>()
+                        .and_then(|s| get_u64(s, "get_star_ts"))
+                        .ok_or("Expected star.get_star_ts to be a number.")?;
+                    completions[usize::from(day.into_inner()) - 1][part] = Some(ts);
+                }
+            }
+        }
+
+        Ok(Member {
+            id,
+            name: json.get("name").and_then(|v| v.get::<String>()).cloned(),
+            local_score: get_u64(json, "local_score").unwrap_or_default(),
+            global_score: get_u64(json, "global_score").unwrap_or_default(),
+            stars: get_u64(json, "stars").unwrap_or_default(),
+            last_star_ts: get_u64(json, "last_star_ts").unwrap_or_default(),
+            completions,
+        })
+    }
+}
+
+impl TryFrom<String> for Leaderboard {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+        let json = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?;
+
+        let year = json
+            .get("event")
+            .and_then(|v| v.get::<String>())
+            .and_then(|e| e.parse().ok())
+            .ok_or("expected JSON document to have a string key `event`.")?;
+
+        let mut members = json
+            .get("members")
+            .and_then(|v| v.get::<HashMap<String, JsonValue>>())
+            .ok_or("expected JSON document to have an object key `members`.")?
+            .values()
+            .map(Member::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        members.sort_unstable_by_key(|m| m.id);
+
+        Ok(Leaderboard { year, members })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{format_duration, render_day, render_rankings, Leaderboard, Order};
+    use crate::day;
+
+    // 2023-12-01 05:00:00 UTC
+    const DAY_1_UNLOCK: u64 = 1_701_406_800;
+
+    fn get_mock_leaderboard() -> Leaderboard {
+        let json = format!(
+            r#"{{
+                "event": "2023",
+                "owner_id": 1,
+                "members": {{
+                    "1": {{
+                        "id": 1, "name": "alice", "local_score": 10, "global_score": 0,
+                        "stars": 2, "last_star_ts": {p2a},
+                        "completion_day_level": {{
+                            "1": {{
+                                "1": {{ "get_star_ts": {p1a}, "star_index": 1 }},
+                                "2": {{ "get_star_ts": {p2a}, "star_index": 2 }}
+                            }}
+                        }}
+                    }},
+                    "2": {{
+                        "id": 2, "name": null, "local_score": 12, "global_score": 80,
+                        "stars": 2, "last_star_ts": {p2b},
+                        "completion_day_level": {{
+                            "1": {{
+                                "1": {{ "get_star_ts": {p1b}, "star_index": 3 }},
+                                "2": {{ "get_star_ts": {p2b}, "star_index": 4 }}
+                            }}
+                        }}
+                    }},
+                    "3": {{
+                        "id": 3, "name": "carol", "local_score": 1, "global_score": 0,
+                        "stars": 1, "last_star_ts": {p1c},
+                        "completion_day_level": {{
+                            "1": {{ "1": {{ "get_star_ts": {p1c}, "star_index": 5 }} }}
+                        }}
+                    }}
+                }}
+            }}"#,
+            p1a = DAY_1_UNLOCK + 300,
+            p2a = DAY_1_UNLOCK + 600,
+            p1b = DAY_1_UNLOCK + 120,
+            p2b = DAY_1_UNLOCK + 3_720,
+            p1c = DAY_1_UNLOCK + 90_000,
+        );
+        Leaderboard::try_from(json).unwrap()
+    }
+
+    #[test]
+    fn parses_leaderboard() {
+        let leaderboard = get_mock_leaderboard();
+        assert_eq!(leaderboard.year, 2023);
+        assert_eq!(leaderboard.members.len(), 3);
+        assert_eq!(leaderboard.unlock_ts(day!(1)), DAY_1_UNLOCK as i64);
+        assert_eq!(leaderboard.members[1].display_name(), "(anonymous user #2)");
+    }
+
+    #[test]
+    fn ranks_by_order() {
+        let leaderboard = get_mock_leaderboard();
+        let ids = |order| -> Vec<u64> {
+            leaderboard
+                .ranked(order)
+                .into_iter()
+                .map(|m| m.id)
+                .collect()
+        };
+        assert_eq!(ids(Order::Local), vec![2, 1, 3]);
+        assert_eq!(ids(Order::Global), vec![2, 1, 3]);
+        // ties are broken by who got their last star first.
+        assert_eq!(ids(Order::Stars), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn renders_rankings() {
+        let rendered = render_rankings(&get_mock_leaderboard(), Order::Local);
+        assert!(rendered.contains("(anonymous user #2)"));
+        assert!(rendered.contains("01:00:00"));
+        assert_eq!(rendered.lines().count(), 4);
+    }
+
+    #[test]
+    fn renders_day_drill_down() {
+        let rendered = render_day(&get_mock_leaderboard(), day!(1));
+        let lines: Vec<_> = rendered.lines().collect();
+        assert!(lines[3].contains("alice"));
+        assert!(lines[3].contains("00:05:00"));
+        assert!(lines[3].contains("00:10:00"));
+        assert!(lines[4].contains("01:02:00"));
+        assert!(lines[5].contains("carol"));
+        assert!(lines[5].contains("1d 01:00:00"));
+    }
+
+    #[test]
+    fn ignores_out_of_order_stars() {
+        let mut member = get_mock_leaderboard().members[0].clone();
+        assert_eq!(member.delta(day!(1)), Some(300));
+        member.completions[0] = [Some(DAY_1_UNLOCK + 600), Some(DAY_1_UNLOCK + 300)];
+        assert_eq!(member.delta(day!(1)), None);
+        assert_eq!(member.median_delta(), None);
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(format_duration(59), "00:00:59");
+        assert_eq!(format_duration(3_661), "01:01:01");
+        assert_eq!(format_duration(90_000), "1d 01:00:00");
+    }
+}
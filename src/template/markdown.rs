@@ -0,0 +1,411 @@
+/// Renders puzzle descriptions, as saved by aoc-cli, to styled and wrapped terminal output.
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_CODE: &str = "\x1b[36m";
+const ANSI_HIGHLIGHT: &str = "\x1b[1;93m";
+const ANSI_DIM: &str = "\x1b[2m";
+
+static PART_TWO_HEADING: &str = "--- Part Two ---";
+static ANSWER_PREFIX: &str = "Your puzzle answer was";
+
+/// A block-level element of a puzzle description.
+#[derive(Debug, PartialEq, Eq)]
+enum Block {
+    Heading(String),
+    Paragraph(String),
+    ListItem(String),
+    Code(Vec<String>),
+    Rule,
+}
+
+/// Returns the number of parts that have been solved according to the puzzle description.
+#[must_use]
+pub fn solved_parts(markdown: &str) -> usize {
+    markdown.matches(ANSWER_PREFIX).count()
+}
+
+/// Returns only the part two section of a puzzle description, if part one has been solved.
+#[must_use]
+pub fn part_two(markdown: &str) -> Option<&str> {
+    if solved_parts(markdown) == 0 {
+        return None;
+    }
+
+    let pos = markdown.find(PART_TWO_HEADING)?;
+    let line_start = markdown[..pos].rfind('\n').map_or(0, |i| i + 1);
+    Some(&markdown[line_start..])
+}
+
+/// Renders a markdown puzzle description to text with ANSI styles, wrapped to `width` columns.
+#[must_use]
+pub fn render(markdown: &str, width: usize) -> String {
+    let width = width.max(20);
+    let mut out = vec![];
+
+    for block in parse_blocks(markdown) {
+        match block {
+            Block::Heading(text) => {
+                out.push(format!("{ANSI_BOLD}{}{ANSI_RESET}", render_inline(&text)));
+            }
+            Block::Paragraph(text) => {
+                out.extend(wrap(&render_inline(&text), width, "", ""));
+            }
+            Block::ListItem(text) => {
+                out.extend(wrap(&render_inline(&text), width, "  • ", "    "));
+            }
+            Block::Code(lines) => {
+                out.extend(
+                    lines
+                        .iter()
+                        .map(|line| format!("    {ANSI_CODE}{}{ANSI_RESET}", strip_tags(line))),
+                );
+            }
+            Block::Rule => out.push(format!("{ANSI_DIM}{}{ANSI_RESET}", "─".repeat(width))),
+        }
+        out.push(String::new());
+    }
+
+    out.join("\n")
+}
+
+fn is_rule(line: &str) -> bool {
+    let line = line.trim();
+    line.len() >= 3 && (line.chars().all(|c| c == '-') || line.chars().all(|c| c == '='))
+}
+
+fn list_item(line: &str) -> Option<&str> {
+    let line = line.trim_start();
+    if let Some(rest) = ["* ", "- ", "+ "].iter().find_map(|m| line.strip_prefix(m)) {
+        return Some(rest);
+    }
+
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits > 0 {
+        return line[digits..].strip_prefix(". ");
+    }
+    None
+}
+
+fn parse_blocks(markdown: &str) -> Vec<Block> {
+    let lines: Vec<&str> = markdown.lines().collect();
+    let mut blocks = vec![];
+    let mut paragraph: Vec<&str> = vec![];
+    let mut i = 0;
+
+    fn flush(paragraph: &mut Vec<&str>, blocks: &mut Vec<Block>) {
+        if !paragraph.is_empty() {
+            blocks.push(Block::Paragraph(paragraph.join(" ")));
+            paragraph.clear();
+        }
+    }
+
+    while i < lines.len() {
+        let line = lines[i];
+        let trimmed = line.trim();
+
+        if trimmed.starts_with("```") {
+            flush(&mut paragraph, &mut blocks);
+            let mut code = vec![];
+            i += 1;
+            while i < lines.len() && !lines[i].trim().starts_with("```") {
+                code.push(lines[i].to_string());
+                i += 1;
+            }
+            blocks.push(Block::Code(code));
+        } else if trimmed.starts_with("<pre>") {
+            flush(&mut paragraph, &mut blocks);
+            let mut code = vec![trimmed.trim_start_matches("<pre>").to_string()];
+            while !code.last().is_some_and(|l| l.contains("</pre>")) && i + 1 < lines.len() {
+                i += 1;
+                code.push(lines[i].to_string());
+            }
+            code.retain(|l| !strip_tags(l).trim().is_empty());
+            blocks.push(Block::Code(code));
+        } else if line.starts_with("    ") && paragraph.is_empty() && !trimmed.is_empty() {
+            let mut code = vec![];
+            while i < lines.len() && (lines[i].starts_with("    ") || lines[i].is_empty()) {
+                code.push(lines[i].get(4..).unwrap_or_default().to_string());
+                i += 1;
+            }
+            while code.last().is_some_and(String::is_empty) {
+                code.pop();
+            }
+            blocks.push(Block::Code(code));
+            continue;
+        } else if trimmed.is_empty() {
+            flush(&mut paragraph, &mut blocks);
+        } else if let Some(heading) = trimmed.strip_prefix('#') {
+            flush(&mut paragraph, &mut blocks);
+            blocks.push(Block::Heading(
+                heading.trim_start_matches('#').trim().into(),
+            ));
+        } else if is_rule(trimmed) {
+            // a rule directly following a single line of text underlines a heading.
+            match paragraph.len() {
+                0 => blocks.push(Block::Rule),
+                _ => {
+                    let heading = paragraph.pop().unwrap_or_default();
+                    flush(&mut paragraph, &mut blocks);
+                    blocks.push(Block::Heading(heading.into()));
+                }
+            }
+        } else if let Some(item) = list_item(line) {
+            flush(&mut paragraph, &mut blocks);
+            let mut text = item.to_string();
+            while i + 1 < lines.len() && lines[i + 1].starts_with("  ") {
+                i += 1;
+                text.push(' ');
+                text.push_str(lines[i].trim());
+            }
+            blocks.push(Block::ListItem(text));
+        } else {
+            paragraph.push(trimmed);
+        }
+
+        i += 1;
+    }
+
+    flush(&mut paragraph, &mut blocks);
+    blocks
+}
+
+fn strip_tags(line: &str) -> String {
+    let mut out = String::with_capacity(line.len());
+    let mut in_tag = false;
+    for c in line.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            c if !in_tag => out.push(c),
+            _ => {}
+        }
+    }
+    out.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&amp;", "&")
+}
+
+/// Renders inline markup: emphasis, code spans, `<em>` highlights, links and escapes.
+fn render_inline(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut styles: Vec<&str> = vec![];
+    let mut chars = text.char_indices().peekable();
+
+    fn push_style(out: &mut String, styles: &mut Vec<&'static str>, style: &'static str) {
+        if let Some(pos) = styles.iter().position(|s| *s == style) {
+            styles.truncate(pos);
+            out.push_str(ANSI_RESET);
+            styles.iter().for_each(|s| out.push_str(s));
+        } else {
+            styles.push(style);
+            out.push_str(style);
+        }
+    }
+
+    while let Some((i, c)) = chars.next() {
+        let rest = &text[i..];
+        match c {
+            '\\' if !styles.contains(&ANSI_CODE) => {
+                if let Some((_, escaped)) = chars.next() {
+                    out.push(escaped);
+                }
+            }
+            '`' => push_style(&mut out, &mut styles, ANSI_CODE),
+            '*' | '_' if rest.starts_with("**") || rest.starts_with("__") => {
+                chars.next();
+                push_style(&mut out, &mut styles, ANSI_BOLD);
+            }
+            '*' if !styles.contains(&ANSI_HIGHLIGHT)
+                && rest[1..].starts_with(char::is_whitespace) =>
+            {
+                out.push(c);
+            }
+            '*' => push_style(&mut out, &mut styles, ANSI_HIGHLIGHT),
+            '_' if styles.contains(&ANSI_ITALIC)
+                || rest[1..].starts_with(|c: char| c.is_alphanumeric()) =>
+            {
+                push_style(&mut out, &mut styles, ANSI_ITALIC);
+            }
+            '<' if rest.starts_with("<em>") || rest.starts_with("</em>") => {
+                let len = if rest.starts_with("<em>") { 4 } else { 5 };
+                (1..len).for_each(|_| {
+                    chars.next();
+                });
+                push_style(&mut out, &mut styles, ANSI_HIGHLIGHT);
+            }
+            '<' if rest.starts_with("<code>") || rest.starts_with("</code>") => {
+                let len = if rest.starts_with("<code>") { 6 } else { 7 };
+                (1..len).for_each(|_| {
+                    chars.next();
+                });
+                push_style(&mut out, &mut styles, ANSI_CODE);
+            }
+            '[' => match parse_link(rest) {
+                Some((label, len)) => {
+                    out.push_str(&render_inline(label));
+                    styles.iter().for_each(|s| out.push_str(s));
+                    while chars.peek().is_some_and(|(j, _)| *j < i + len) {
+                        chars.next();
+                    }
+                }
+                None => out.push(c),
+            },
+            c => out.push(c),
+        }
+    }
+
+    if !styles.is_empty() {
+        out.push_str(ANSI_RESET);
+    }
+    out
+}
+
+/// Parses a `[label](url)` link at the start of `text`, returning the label and the link length.
+/// The label ends at the first `]`, which has to be followed by the `(` of the url.
+fn parse_link(text: &str) -> Option<(&str, usize)> {
+    let label_end = text.find(']')?;
+    if !text[label_end + 1..].starts_with('(') {
+        return None;
+    }
+    let url_end = text[label_end..].find(')')? + label_end;
+    Some((&text[1..label_end], url_end + 1))
+}
+
+/// Returns the number of visible characters in `text`, skipping ANSI escape sequences.
+fn visible_len(text: &str) -> usize {
+    let mut len = 0;
+    let mut in_escape = false;
+    for c in text.chars() {
+        match c {
+            '\x1b' => in_escape = true,
+            'm' if in_escape => in_escape = false,
+            _ if in_escape => {}
+            _ => len += 1,
+        }
+    }
+    len
+}
+
+/// Word-wraps styled text to `width` columns, prefixing the first and following lines.
+fn wrap(text: &str, width: usize, first_prefix: &str, prefix: &str) -> Vec<String> {
+    let mut lines = vec![];
+    let mut line = first_prefix.to_string();
+    let mut line_len = visible_len(first_prefix);
+    let mut is_empty = true;
+
+    for word in text.split_whitespace() {
+        let word_len = visible_len(word);
+        if !is_empty && line_len + 1 + word_len > width {
+            lines.push(line);
+            line = prefix.to_string();
+            line_len = visible_len(prefix);
+            is_empty = true;
+        }
+        if !is_empty {
+            line.push(' ');
+            line_len += 1;
+        }
+        line.push_str(word);
+        line_len += word_len;
+        is_empty = false;
+    }
+
+    lines.push(line);
+    lines
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{
+        parse_blocks, parse_link, part_two, render, render_inline, visible_len, wrap, Block,
+    };
+    use super::{ANSI_CODE, ANSI_HIGHLIGHT};
+    use crate::template::ANSI_RESET;
+
+    const PUZZLE: &str = r"\--- Day 1: Not Quite Lisp ---
+----------
+
+Santa needs *fifty stars* by December 25th. An opening parenthesis, `(`, means he
+should go up one floor.
+
+For example:
+
+* `(())` and `()()` both result in floor `0`.
+* `)))` results in floor `-3`.
+
+```
+(()(()(
+```
+
+Your puzzle answer was `232`.
+
+\--- Part Two ---
+----------
+
+Find the position of the first character that causes him to enter the basement.
+
+Your puzzle answer was `1783`.
+";
+
+    #[test]
+    fn parses_blocks() {
+        let blocks = parse_blocks(PUZZLE);
+        assert_eq!(
+            blocks[0],
+            Block::Heading(r"\--- Day 1: Not Quite Lisp ---".into())
+        );
+        assert!(matches!(&blocks[1], Block::Paragraph(p) if p.ends_with("up one floor.")));
+        assert_eq!(blocks[2], Block::Paragraph("For example:".into()));
+        assert!(matches!(&blocks[3], Block::ListItem(_)));
+        assert!(matches!(&blocks[4], Block::ListItem(_)));
+        assert_eq!(blocks[5], Block::Code(vec!["(()(()(".into()]));
+        assert_eq!(blocks[7], Block::Heading(r"\--- Part Two ---".into()));
+    }
+
+    #[test]
+    fn renders_inline_styles() {
+        assert_eq!(
+            render_inline("a *b* `c` <em>d</em> [e](https://example.com) \\-"),
+            format!(
+                "a {ANSI_HIGHLIGHT}b{ANSI_RESET} {ANSI_CODE}c{ANSI_RESET} \
+                {ANSI_HIGHLIGHT}d{ANSI_RESET} e -"
+            )
+        );
+    }
+
+    #[test]
+    fn renders_brackets_before_links() {
+        assert_eq!(parse_link("[1] and [x](u)"), None);
+        assert_eq!(parse_link("[x](u) and [1]"), Some(("x", 6)));
+        assert_eq!(render_inline("[1] and [x](u)"), "[1] and x");
+    }
+
+    #[test]
+    fn wraps_to_width_ignoring_styles() {
+        let text = render_inline("one *two* three four");
+        let lines = wrap(&text, 10, "", "");
+        assert_eq!(lines.len(), 2);
+        assert!(lines.iter().all(|l| visible_len(l) <= 10));
+    }
+
+    #[test]
+    fn renders_without_markup() {
+        let rendered = render(PUZZLE, 40);
+        assert!(rendered.contains("--- Day 1: Not Quite Lisp ---"));
+        assert!(rendered.contains("  • "));
+        assert!(!rendered.contains("```"));
+        assert!(rendered.lines().all(|l| visible_len(l) <= 40));
+    }
+
+    #[test]
+    fn extracts_part_two_when_part_one_is_solved() {
+        let part_two = part_two(PUZZLE).unwrap();
+        assert!(part_two.starts_with(r"\--- Part Two ---"));
+        assert!(!part_two.contains("Day 1"));
+    }
+
+    #[test]
+    fn does_not_extract_part_two_when_unsolved() {
+        assert_eq!(part_two("\\--- Day 1 ---\n\nNo answers yet."), None);
+    }
+}
@@ -1,44 +1,194 @@
-use std::{env, fs};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::{env, fs, io, process};
 
 pub mod aoc_cli;
+#[cfg(feature = "today")]
+pub mod clock;
 pub mod commands;
 pub mod runner;
 
 pub use day::*;
 
 mod day;
+pub mod leaderboard;
+mod markdown;
 mod readme_benchmarks;
+mod readme_stars;
 mod run_multi;
+mod status;
 mod timings;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// An error which can be returned when reading a puzzle file from the `data` folder.
+#[derive(Debug)]
+pub enum ReadFileError {
+    /// The file does not exist at the expected path.
+    Missing {
+        path: PathBuf,
+        folder: String,
+        day: Day,
+    },
+    /// The file exists, but contains nothing but whitespace.
+    Empty {
+        path: PathBuf,
+        folder: String,
+        day: Day,
+    },
+    /// The file exists, but could not be read.
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl ReadFileError {
+    fn hint(folder: &str, day: Day) -> String {
+        match folder {
+            "inputs" => format!("Run `cargo download {day}` to fetch it."),
+            "examples" => "Copy the example from the puzzle description into it.".into(),
+            _ => format!("Create it in `data/{folder}`."),
+        }
+    }
+}
+
+impl std::error::Error for ReadFileError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ReadFileError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+impl Display for ReadFileError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ReadFileError::Missing { path, folder, day } => write!(
+                f,
+                "could not find file \"{}\". {}",
+                path.display(),
+                ReadFileError::hint(folder, *day)
+            ),
+            ReadFileError::Empty { path, folder, day } => write!(
+                f,
+                "file \"{}\" is empty. {}",
+                path.display(),
+                ReadFileError::hint(folder, *day)
+            ),
+            ReadFileError::Io { path, source } => {
+                write!(f, "could not read file \"{}\": {source}", path.display())
+            }
+        }
+    }
+}
+
+fn data_path(folder: &str, file_name: &str) -> PathBuf {
+    let cwd = env::current_dir().unwrap();
+    cwd.join("data").join(folder).join(file_name)
+}
+
+fn read_path(path: &Path, folder: &str, day: Day) -> Result<String, ReadFileError> {
+    match fs::read_to_string(path) {
+        Ok(contents) if contents.trim().is_empty() => Err(ReadFileError::Empty {
+            path: path.to_path_buf(),
+            folder: folder.into(),
+            day,
+        }),
+        Ok(contents) => Ok(contents),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(ReadFileError::Missing {
+            path: path.to_path_buf(),
+            folder: folder.into(),
+            day,
+        }),
+        Err(source) => Err(ReadFileError::Io {
+            path: path.to_path_buf(),
+            source,
+        }),
+    }
+}
+
+/// Reads a text file to a string, returning a [`ReadFileError`] if it is missing or empty.
+pub fn try_read_file(folder: &str, day: Day) -> Result<String, ReadFileError> {
+    read_path(&data_path(folder, &format!("{day}.txt")), folder, day)
+}
+
+/// Reads a text file to a string, appending a part suffix. E.g. like `01-2.txt`.
+/// Returns a [`ReadFileError`] if it is missing or empty.
+pub fn try_read_file_part(folder: &str, day: Day, part: u8) -> Result<String, ReadFileError> {
+    read_path(
+        &data_path(folder, &format!("{day}-{part}.txt")),
+        folder,
+        day,
+    )
+}
+
+/// Unwraps the contents of a file, treating an empty file as an empty string.
+fn contents_or_empty(result: Result<String, ReadFileError>) -> String {
+    match result {
+        Ok(contents) => contents,
+        // scaffolded examples start out empty, and their tests should fail rather than panic.
+        Err(ReadFileError::Empty { .. }) => String::new(),
+        Err(e) => panic!("{e}"),
+    }
+}
+
 /// Helper function that reads a text file to a string.
+/// Panics if the file is missing, and returns an empty string if it is empty.
 #[must_use]
 pub fn read_file(folder: &str, day: Day) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    contents_or_empty(try_read_file(folder, day))
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
+/// Panics if the file is missing, and returns an empty string if it is empty.
 #[must_use]
 pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-    let filepath = cwd
-        .join("data")
-        .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    contents_or_empty(try_read_file_part(folder, day, part))
+}
+
+/// Reads the puzzle input for a day.
+///
+/// If the input is missing or empty and an Advent of Code session is configured, the input is
+/// downloaded via aoc-cli first. Otherwise, exits with a message that names the expected path.
+#[must_use]
+pub fn read_input(day: Day) -> String {
+    let err = match try_read_file("inputs", day) {
+        Ok(input) => return input,
+        Err(err) => err,
+    };
+
+    let can_download = matches!(
+        err,
+        ReadFileError::Missing { .. } | ReadFileError::Empty { .. }
+    ) && aoc_cli::has_session()
+        && aoc_cli::check().is_ok();
+
+    if !can_download {
+        eprintln!("Error: {err}");
+        process::exit(1);
+    }
+
+    eprintln!("{err}");
+    eprintln!("Found an Advent of Code session, downloading input for day {day}...");
+
+    if let Err(e) = aoc_cli::download(day) {
+        eprintln!("failed to call aoc-cli: {e}");
+        process::exit(1);
+    }
+
+    try_read_file("inputs", day).unwrap_or_else(|e| {
+        eprintln!("Error: {e}");
+        process::exit(1);
+    })
 }
 
 /// Creates the constant `DAY` and sets up the input and runner for each part.
 ///
 /// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// Alternatively, `visualize = <function>` runs both parts and then saves the image or animation
+/// returned by the function if the binary is called with `--visualize <path>`. Other solutions
+/// warn that they ignore the flag.
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
@@ -50,8 +200,11 @@ macro_rules! solution {
     ($day:expr, 2) => {
         $crate::solution!(@impl $day, [part_two, 2]);
     };
+    ($day:expr, visualize = $visualize:expr) => {
+        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]; $visualize);
+    };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, $( [$func:expr, $part:expr] )* $(; $visualize:expr)?) => {
         /// The current day.
         const DAY: $crate::template::Day = $crate::day!($day);
 
@@ -61,8 +214,56 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
+            let input = $crate::template::read_input(DAY);
             $( run_part($func, &input, DAY, $part); )*
+            $crate::solution!(@visualize input $(, $visualize)?);
         }
     };
+
+    (@visualize $input:ident) => {
+        $crate::template::runner::warn_unused_visualization();
+    };
+    (@visualize $input:ident, $visualize:expr) => {
+        $crate::template::runner::run_visualization($visualize, &$input);
+    };
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{contents_or_empty, read_path, ReadFileError};
+    use crate::day;
+    use std::{env, fs};
+
+    #[test]
+    fn reports_missing_files() {
+        let path = env::temp_dir().join("aoc-read-file-missing.txt");
+        let err = read_path(&path, "inputs", day!(3)).unwrap_err();
+        assert!(matches!(err, ReadFileError::Missing { .. }));
+
+        let message = err.to_string();
+        assert!(message.contains(&path.display().to_string()));
+        assert!(message.contains("cargo download 03"));
+    }
+
+    #[test]
+    fn reports_empty_files() {
+        let path = env::temp_dir().join("aoc-read-file-empty.txt");
+        fs::write(&path, "\n").unwrap();
+        let err = read_path(&path, "examples", day!(3)).unwrap_err();
+        fs::remove_file(&path).unwrap();
+
+        assert!(matches!(err, ReadFileError::Empty { .. }));
+        assert!(err.to_string().contains("is empty"));
+        assert_eq!(contents_or_empty(Err(err)), "");
+    }
+
+    #[test]
+    fn reads_files() {
+        let path = env::temp_dir().join("aoc-read-file-ok.txt");
+        fs::write(&path, "42").unwrap();
+        let contents = read_path(&path, "inputs", day!(3)).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(contents, "42");
+    }
 }
@@ -2,10 +2,10 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
+use crate::template::run_multi::get_path_for_module;
 use crate::template::timings::Timings;
-use crate::template::Day;
 
-static MARKER: &str = "<!--- benchmarking table --->";
+pub static MARKER: &str = "<!--- benchmarking table --->";
 
 #[allow(dead_code)]
 #[derive(Debug)]
@@ -21,17 +21,13 @@ impl From<std::io::Error> for Error {
 }
 
 pub struct TablePosition {
-    pos_start: usize,
-    pos_end: usize,
+    pub pos_start: usize,
+    pub pos_end: usize,
 }
 
-#[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
-}
-
-fn locate_table(readme: &str) -> Result<TablePosition, Error> {
-    let matches: Vec<_> = readme.match_indices(MARKER).collect();
+/// Locates the section enclosed by a pair of `marker` comments.
+pub fn locate_table(readme: &str, marker: &str) -> Result<TablePosition, Error> {
+    let matches: Vec<_> = readme.match_indices(marker).collect();
 
     if matches.len() > 2 {
         return Err(Error::Parser(
@@ -64,7 +60,7 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     ];
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
+        let path = get_path_for_module(timing.day);
         lines.push(format!(
             "| [Day {}]({}) | `{}` | `{}` |",
             timing.day.into_inner(),
@@ -82,7 +78,7 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
 }
 
 fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
-    let positions = locate_table(s)?;
+    let positions = locate_table(s, MARKER)?;
     let table = construct_table("##", timings, total_millis);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
@@ -169,9 +165,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/days/day01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/days/day02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/days/day04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -0,0 +1,101 @@
+/// Module that updates the readme with a summary of the collected stars.
+/// The summary is placed next to the benchmarking table.
+use std::fs;
+
+use crate::template::readme_benchmarks::{self, locate_table, Error};
+use crate::template::status::{total_stars, DayStatus};
+
+static MARKER: &str = "<!--- stars summary --->";
+
+fn construct_summary(statuses: &[DayStatus]) -> String {
+    let stars = total_stars(statuses);
+    let solved = statuses.iter().filter(|s| s.stars == 2).count();
+
+    let calendar: String = statuses
+        .iter()
+        .map(|s| match s.stars {
+            2 => '★',
+            1 => '☆',
+            _ => '·',
+        })
+        .collect();
+
+    [
+        MARKER.into(),
+        format!("![Stars](https://img.shields.io/badge/stars%20⭐-{stars}%2F50-yellow)"),
+        String::new(),
+        format!("**{stars}/50 stars** · {solved} days complete · `{calendar}`"),
+        MARKER.into(),
+    ]
+    .join("\n")
+}
+
+fn update_content(s: &mut String, statuses: &[DayStatus]) -> Result<(), Error> {
+    let summary = construct_summary(statuses);
+
+    if s.contains(MARKER) {
+        let positions = locate_table(s, MARKER)?;
+        s.replace_range(positions.pos_start..positions.pos_end, &summary);
+    } else {
+        let positions = locate_table(s, readme_benchmarks::MARKER)?;
+        s.insert_str(positions.pos_start, &format!("{summary}\n\n"));
+    }
+
+    Ok(())
+}
+
+pub fn update(statuses: &[DayStatus]) -> Result<(), Error> {
+    let path = "README.md";
+    let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
+    update_content(&mut readme, statuses)?;
+    fs::write(path, &readme)?;
+    Ok(())
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{update_content, MARKER};
+    use crate::template::{all_days, readme_benchmarks, status::DayStatus};
+
+    fn get_mock_statuses() -> Vec<DayStatus> {
+        all_days()
+            .map(|day| DayStatus {
+                day,
+                has_solution: true,
+                has_input: true,
+                has_tests: true,
+                stars: if day <= 2 { 2 } else { u8::from(day == 3) },
+                timing: None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn inserts_summary_before_benchmarks() {
+        let bench = readme_benchmarks::MARKER;
+        let mut s = format!("foo\n{bench}\n{bench}\nbaz");
+        update_content(&mut s, &get_mock_statuses()).unwrap();
+
+        assert!(s.starts_with(&format!("foo\n{MARKER}\n")));
+        assert!(s.contains("**5/50 stars** · 2 days complete · `★★☆"));
+        assert!(s.ends_with(&format!("{MARKER}\n\n{bench}\n{bench}\nbaz")));
+    }
+
+    #[test]
+    fn updates_existing_summary() {
+        let bench = readme_benchmarks::MARKER;
+        let mut s = format!("foo\n{bench}{bench}");
+        update_content(&mut s, &get_mock_statuses()).unwrap();
+        update_content(&mut s, &get_mock_statuses()).unwrap();
+
+        assert_eq!(s.matches(MARKER).count(), 2);
+        assert_eq!(s.matches("5/50 stars").count(), 1);
+    }
+
+    #[test]
+    #[should_panic]
+    fn errors_if_no_marker_present() {
+        let mut s = "# readme".to_string();
+        update_content(&mut s, &get_mock_statuses()).unwrap();
+    }
+}
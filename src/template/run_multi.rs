@@ -64,6 +64,12 @@ pub fn get_path_for_bin(day: Day) -> String {
     format!("./src/bin/{day}.rs")
 }
 
+/// Returns the path of the library module holding the solution of `day`.
+#[must_use]
+pub fn get_path_for_module(day: Day) -> String {
+    format!("./src/days/day{day}.rs")
+}
+
 /// All solutions live in isolated binaries.
 /// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
 pub mod child_commands {
@@ -174,7 +180,7 @@ pub mod child_commands {
             .split(" samples)")
             .next()?
             .split('(')
-            .last()?
+            .next_back()?
             .split('@')
             .next()?
             .trim();
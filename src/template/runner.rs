@@ -2,6 +2,7 @@
 use std::fmt::Display;
 use std::hint::black_box;
 use std::io::{stdout, Write};
+use std::path::{Path, PathBuf};
 use std::process::Output;
 use std::time::{Duration, Instant};
 use std::{cmp, env, process};
@@ -9,16 +10,97 @@ use std::{cmp, env, process};
 use crate::template::ANSI_BOLD;
 use crate::template::{aoc_cli, Day, ANSI_ITALIC, ANSI_RESET};
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+/// The return value of a solution part: either an `Option` of the answer, or a `Result` whose
+/// error is shown in place of the answer.
+pub trait PartResult {
+    type Answer: Display;
+
+    fn answer(&self) -> Option<&Self::Answer>;
+
+    fn error(&self) -> Option<String> {
+        None
+    }
+}
+
+impl<T: Display> PartResult for Option<T> {
+    type Answer = T;
+
+    fn answer(&self) -> Option<&T> {
+        self.as_ref()
+    }
+}
+
+impl<T: Display, E: Display> PartResult for Result<T, E> {
+    type Answer = T;
+
+    fn answer(&self) -> Option<&T> {
+        self.as_ref().ok()
+    }
+
+    fn error(&self) -> Option<String> {
+        self.as_ref().err().map(ToString::to_string)
+    }
+}
+
+pub fn run_part<I: Copy, R: PartResult>(func: impl Fn(I) -> R, input: I, day: Day, part: u8) {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let (result, duration, samples) = run_timed(func, input, |result| {
+        print_result(&result.answer(), &part_str, "");
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    print_result(
+        &result.answer(),
+        &part_str,
+        &format_duration(&duration, samples),
+    );
+
+    if let Some(error) = result.error() {
+        eprintln!("{error}");
+    }
+
+    if let Some(answer) = result.answer() {
+        submit_result(answer, day, part);
+    }
+}
+
+/// A visualization of a solution, which can be written to a file.
+pub trait Save {
+    type Error: Display;
+
+    /// Writes to `path`, choosing the format from its extension.
+    fn save(&self, path: &Path) -> Result<(), Self::Error>;
+}
+
+/// Saves the image or animation created by `func` to the path given with `--visualize <path>`.
+/// Does nothing if the flag is missing, and exits with an error if the visualization fails.
+pub fn run_visualization<V: Save, E: Display>(func: impl Fn(&str) -> Result<V, E>, input: &str) {
+    let args: Vec<String> = env::args().collect();
+    let Some(index) = args.iter().position(|x| x == "--visualize") else {
+        return;
+    };
+    let Some(path) = args.get(index + 1).map(PathBuf::from) else {
+        eprintln!("Unexpected command-line input. Format: cargo solve 1 --visualize out.png");
+        process::exit(1);
+    };
+
+    let saved = match func(input) {
+        Ok(visualization) => visualization.save(&path).map_err(|err| err.to_string()),
+        Err(err) => Err(err.to_string()),
+    };
+    match saved {
+        Ok(()) => println!("Saved visualization to {}", path.display()),
+        Err(err) => {
+            eprintln!("Could not visualize: {err}");
+            process::exit(1);
+        }
+    }
+}
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+/// Warns about a `--visualize` flag passed to a solution without a visualization.
+pub fn warn_unused_visualization() {
+    if env::args().any(|x| x == "--visualize") {
+        eprintln!("This solution has no visualization, ignoring --visualize.");
     }
 }
 
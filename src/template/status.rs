@@ -0,0 +1,208 @@
+/// Module that collects the progress of every day from the files in the repository.
+use std::fs;
+
+use crate::template::run_multi::get_path_for_module;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{all_days, markdown, Day, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+
+const ANSI_GOLD: &str = "\x1b[93m";
+const ANSI_DIM: &str = "\x1b[2m";
+
+static BOTH_PARTS_COMPLETE: &str = "Both parts of this puzzle are complete";
+
+/// Progress of a single day.
+#[derive(Clone, Debug)]
+pub struct DayStatus {
+    pub day: Day,
+    pub has_solution: bool,
+    pub has_input: bool,
+    pub has_tests: bool,
+    pub stars: u8,
+    pub timing: Option<Timing>,
+}
+
+impl DayStatus {
+    fn is_untested(&self) -> bool {
+        self.has_solution && !self.has_tests
+    }
+}
+
+/// Counts the stars of a day from its puzzle description, which lists the submitted answers.
+fn count_stars(puzzle: &str) -> u8 {
+    if puzzle.contains(BOTH_PARTS_COMPLETE) {
+        return 2;
+    }
+    u8::try_from(markdown::solved_parts(puzzle).min(2)).unwrap_or_default()
+}
+
+/// Collects the status of every day from solutions, inputs, puzzle descriptions and timings.
+pub fn collect(timings: &Timings) -> Vec<DayStatus> {
+    all_days()
+        .map(|day| {
+            let solution = fs::read_to_string(get_path_for_module(day)).ok();
+            let puzzle = fs::read_to_string(format!("data/puzzles/{day}.md")).unwrap_or_default();
+
+            DayStatus {
+                day,
+                has_solution: solution.is_some(),
+                has_input: fs::read_to_string(format!("data/inputs/{day}.txt"))
+                    .is_ok_and(|input| !input.trim().is_empty()),
+                has_tests: solution.is_some_and(|s| s.contains("#[test]")),
+                stars: count_stars(&puzzle),
+                timing: timings.data.iter().find(|t| t.day == day).cloned(),
+            }
+        })
+        .collect()
+}
+
+/// Sums up the stars of all days.
+pub fn total_stars(statuses: &[DayStatus]) -> u32 {
+    statuses.iter().map(|s| u32::from(s.stars)).sum()
+}
+
+fn format_millis(nanos: f64) -> String {
+    format!("{:.2}ms", nanos / 1_000_000_f64)
+}
+
+fn render_cell(status: &DayStatus) -> String {
+    if !status.has_solution && status.stars == 0 {
+        return format!("{ANSI_DIM}{} ·{}{ANSI_RESET}", status.day, " ".repeat(13));
+    }
+
+    let stars = format!(
+        "{ANSI_GOLD}{}{ANSI_RESET}{}",
+        "★".repeat(status.stars.into()),
+        "☆".repeat((2 - status.stars).into())
+    );
+
+    let time = status
+        .timing
+        .as_ref()
+        .map_or_else(|| "-".into(), |t| format_millis(t.total_nanos));
+
+    let flags = format!(
+        "{}{}",
+        if status.is_untested() { "?" } else { " " },
+        if status.has_input { " " } else { "!" }
+    );
+
+    format!(
+        "{ANSI_BOLD}{}{ANSI_RESET} {stars} {time:>9}{flags}",
+        status.day
+    )
+}
+
+/// Renders the status of all days as a calendar grid, followed by a summary.
+pub fn render(statuses: &[DayStatus]) -> String {
+    let mut lines: Vec<String> = statuses
+        .chunks(5)
+        .map(|week| week.iter().map(render_cell).collect::<Vec<_>>().join("  "))
+        .collect();
+
+    lines.push(String::new());
+    lines.push(format!(
+        "{ANSI_BOLD}Stars:{ANSI_RESET} {}/50",
+        total_stars(statuses)
+    ));
+
+    let timings = Timings {
+        data: statuses.iter().filter_map(|s| s.timing.clone()).collect(),
+    };
+    if !timings.data.is_empty() {
+        lines.push(format!(
+            "{ANSI_BOLD}Benchmarks:{ANSI_RESET} {ANSI_ITALIC}{:.2}ms{ANSI_RESET} total over {} days",
+            timings.total_millis(),
+            timings.data.len()
+        ));
+
+        let mut slowest = timings.data.clone();
+        slowest.sort_by(|a, b| b.total_nanos.total_cmp(&a.total_nanos));
+        lines.push(format!(
+            "{ANSI_BOLD}Slowest:{ANSI_RESET} {}",
+            slowest
+                .iter()
+                .take(3)
+                .map(|t| format!("day {} ({})", t.day, format_millis(t.total_nanos)))
+                .collect::<Vec<_>>()
+                .join(", ")
+        ));
+    }
+
+    let untested: Vec<_> = statuses
+        .iter()
+        .filter(|s| s.is_untested())
+        .map(|s| s.day.to_string())
+        .collect();
+    if !untested.is_empty() {
+        lines.push(format!(
+            "{ANSI_BOLD}Untested (?):{ANSI_RESET} {}",
+            untested.join(", ")
+        ));
+    }
+
+    let missing_input: Vec<_> = statuses
+        .iter()
+        .filter(|s| s.has_solution && !s.has_input)
+        .map(|s| s.day.to_string())
+        .collect();
+    if !missing_input.is_empty() {
+        lines.push(format!(
+            "{ANSI_BOLD}Missing input (!):{ANSI_RESET} {}",
+            missing_input.join(", ")
+        ));
+    }
+
+    lines.join("\n")
+}
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{count_stars, render, total_stars, DayStatus};
+    use crate::template::all_days;
+    use crate::template::timings::Timing;
+
+    fn get_mock_statuses() -> Vec<DayStatus> {
+        all_days()
+            .map(|day| DayStatus {
+                day,
+                has_solution: day <= 3,
+                has_input: day <= 2,
+                has_tests: day != 2,
+                stars: if day <= 2 { 2 } else { 0 },
+                timing: (day == 1).then(|| Timing {
+                    day,
+                    part_1: Some("1ms".into()),
+                    part_2: Some("2ms".into()),
+                    total_nanos: 3_000_000_f64,
+                }),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn counts_stars_from_answers() {
+        assert_eq!(count_stars(""), 0);
+        assert_eq!(count_stars("Your puzzle answer was `1`."), 1);
+        assert_eq!(
+            count_stars("Your puzzle answer was `1`.\n\nYour puzzle answer was `2`."),
+            2
+        );
+        assert_eq!(
+            count_stars("Your puzzle answer was `1`.\n\nBoth parts of this puzzle are complete!"),
+            2
+        );
+    }
+
+    #[test]
+    fn renders_calendar_grid() {
+        let statuses = get_mock_statuses();
+        let rendered = render(&statuses);
+
+        assert_eq!(total_stars(&statuses), 4);
+        assert!(rendered.contains("4/50"));
+        assert!(rendered.contains("3.00ms"));
+        assert!(rendered.contains("Untested (?):\u{1b}[0m 02"));
+        assert!(rendered.contains("Missing input (!):\u{1b}[0m 03"));
+        assert_eq!(rendered.lines().take_while(|l| !l.is_empty()).count(), 5);
+    }
+}
@@ -51,7 +51,7 @@ impl Timings {
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(|a| a.day);
         Timings { data }
     }
 
@@ -61,9 +61,9 @@ impl Timings {
     }
 
     pub fn is_day_complete(&self, day: Day) -> bool {
-        self.data
-            .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+        self.data.iter().any(|t| {
+            t.day == day && t.part_1.is_some() && (t.part_2.is_some() || !day.has_part_two())
+        })
     }
 }
 
@@ -285,7 +285,7 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(timings.is_day_complete(day!(1)), true);
         }
 
         #[test]
@@ -299,7 +299,30 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
+        }
+
+        #[test]
+        fn handles_single_part_days() {
+            let timings = Timings {
+                data: vec![
+                    Timing {
+                        day: day!(24),
+                        part_1: Some("1ms".into()),
+                        part_2: None,
+                        total_nanos: 1_000_000_000_f64,
+                    },
+                    Timing {
+                        day: day!(25),
+                        part_1: Some("1ms".into()),
+                        part_2: None,
+                        total_nanos: 1_000_000_000_f64,
+                    },
+                ],
+            };
+
+            assert_eq!(timings.is_day_complete(day!(24)), false);
+            assert_eq!(timings.is_day_complete(day!(25)), true);
         }
 
         #[test]
@@ -313,7 +336,7 @@ mod tests {
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(timings.is_day_complete(day!(1)), false);
         }
     }
 
@@ -0,0 +1,24 @@
+//! Random inputs shared by the tests and the benchmarks, which include this file by path.
+
+/// Generates `height` lines of `width` lights, drawn as `#` for on and `.` for off, with about a
+/// third of them on. The same seed always gives the same yard.
+pub fn lights(width: usize, height: usize, seed: u64) -> String {
+    let mut state = seed;
+    let rows: Vec<String> = (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| {
+                    state = state
+                        .wrapping_mul(6364136223846793005)
+                        .wrapping_add(1442695040888963407);
+                    if (state >> 33).is_multiple_of(3) {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect()
+        })
+        .collect();
+    rows.join("\n")
+}
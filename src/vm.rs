@@ -0,0 +1,431 @@
+//! A register machine for the assembly puzzles.
+//!
+//! A program is a list of instructions of any type implementing [`Instruction`], which updates
+//! the [`Registers`] and tells the [`Machine`] where to continue. [`Instr`] is the instruction
+//! set of 2015's day 23; later puzzles can define their own and reuse the machine.
+
+use std::fmt::{self, Display};
+use std::ops::{Index, IndexMut};
+
+use crate::parse::{FromField, Line, ParseError};
+
+/// A register, named by a lowercase letter.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Register(u8);
+
+impl Register {
+    /// Returns the register named `name`, if it is a lowercase letter.
+    pub fn new(name: char) -> Option<Self> {
+        name.is_ascii_lowercase().then(|| Self(name as u8 - b'a'))
+    }
+
+    /// Returns the name of the register.
+    pub fn name(self) -> char {
+        (b'a' + self.0) as char
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromField<'_> for Register {
+    fn from_field(field: &str) -> Result<Self, String> {
+        let mut chars = field.chars();
+        match (chars.next().and_then(Register::new), chars.next()) {
+            (Some(register), None) => Ok(register),
+            _ => Err(format!("invalid register {field:?}")),
+        }
+    }
+}
+
+/// The values of all registers, which start at zero.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Registers([i64; 26]);
+
+impl Index<Register> for Registers {
+    type Output = i64;
+
+    fn index(&self, register: Register) -> &Self::Output {
+        &self.0[register.0 as usize]
+    }
+}
+
+impl IndexMut<Register> for Registers {
+    fn index_mut(&mut self, register: Register) -> &mut Self::Output {
+        &mut self.0[register.0 as usize]
+    }
+}
+
+impl Index<char> for Registers {
+    type Output = i64;
+
+    /// # Panics
+    /// Panics if `name` is not a lowercase letter.
+    fn index(&self, name: char) -> &Self::Output {
+        &self[Register::new(name).expect("register names are lowercase letters")]
+    }
+}
+
+impl IndexMut<char> for Registers {
+    fn index_mut(&mut self, name: char) -> &mut Self::Output {
+        &mut self[Register::new(name).expect("register names are lowercase letters")]
+    }
+}
+
+/// Shows all registers that are not zero, e.g. `a=1 b=7`.
+impl Display for Registers {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut nonzero = (0..26).map(Register).filter(|&r| self[r] != 0).peekable();
+        if nonzero.peek().is_none() {
+            return f.write_str("-");
+        }
+        for (i, register) in nonzero.enumerate() {
+            if i > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{register}={}", self[register])?;
+        }
+        Ok(())
+    }
+}
+
+/// Where execution continues after an instruction.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Control {
+    /// Continue with the following instruction.
+    Next,
+    /// Continue with the instruction at the given offset from the current one.
+    Jump(isize),
+    /// Stop the program.
+    Halt,
+}
+
+/// An instruction that a [`Machine`] can execute.
+pub trait Instruction {
+    /// Executes the instruction, returning where to continue.
+    fn execute(&self, registers: &mut Registers) -> Control;
+}
+
+/// An error which can occur while loading or running a program.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VmError {
+    /// The program could not be parsed.
+    Parse(ParseError),
+    /// The program did not halt within the step limit.
+    StepLimit {
+        /// The maximum number of steps.
+        limit: usize,
+        /// The instruction that would have been executed next.
+        pc: usize,
+    },
+}
+
+impl std::error::Error for VmError {}
+
+impl Display for VmError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VmError::Parse(err) => write!(f, "{err}"),
+            VmError::StepLimit { limit, pc } => {
+                write!(
+                    f,
+                    "no halt within {limit} steps, stopped at instruction {pc}"
+                )
+            }
+        }
+    }
+}
+
+impl From<ParseError> for VmError {
+    fn from(err: ParseError) -> Self {
+        VmError::Parse(err)
+    }
+}
+
+/// The state of a machine right before it executes an instruction, as passed to the tracer of
+/// [`Machine::run_traced`].
+#[derive(Debug)]
+pub struct Trace<'a, I> {
+    /// The number of instructions executed so far.
+    pub step: usize,
+    /// The index of the instruction.
+    pub pc: usize,
+    /// The instruction about to be executed.
+    pub instruction: &'a I,
+    /// The registers before the instruction is executed.
+    pub registers: &'a Registers,
+}
+
+/// Shows the step, the instruction and the registers, e.g. `   3 @ 1: jio a, +2   a=1`.
+impl<I: Display> Display for Trace<'_, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let instruction = self.instruction.to_string();
+        write!(
+            f,
+            "{:>4} @ {}: {instruction:<12} {}",
+            self.step, self.pc, self.registers
+        )
+    }
+}
+
+/// Executes a program until it halts, which happens when an instruction returns
+/// [`Control::Halt`] or the program counter leaves the program.
+#[derive(Clone, Debug)]
+pub struct Machine<'p, I> {
+    program: &'p [I],
+    registers: Registers,
+    pc: Option<usize>,
+    steps: usize,
+    step_limit: Option<usize>,
+}
+
+impl<'p, I: Instruction> Machine<'p, I> {
+    /// Creates a machine at the start of `program`, with all registers set to zero.
+    pub fn new(program: &'p [I]) -> Self {
+        Self {
+            program,
+            registers: Registers::default(),
+            pc: Some(0),
+            steps: 0,
+            step_limit: None,
+        }
+    }
+
+    /// Sets the initial value of a register.
+    ///
+    /// # Panics
+    /// Panics if `name` is not a lowercase letter.
+    pub fn with_register(mut self, name: char, value: i64) -> Self {
+        self.registers[name] = value;
+        self
+    }
+
+    /// Makes [`Machine::run`] fail once it has executed `limit` instructions without halting.
+    pub fn with_step_limit(mut self, limit: usize) -> Self {
+        self.step_limit = Some(limit);
+        self
+    }
+
+    /// Returns the current values of the registers.
+    pub fn registers(&self) -> &Registers {
+        &self.registers
+    }
+
+    /// Returns the index of the next instruction, or `None` once the machine has halted.
+    pub fn pc(&self) -> Option<usize> {
+        self.pc.filter(|&pc| pc < self.program.len())
+    }
+
+    /// Returns the number of instructions executed so far.
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Executes a single instruction. Returns `false` if the machine has already halted.
+    pub fn step(&mut self) -> bool {
+        let Some(pc) = self.pc() else {
+            return false;
+        };
+        self.steps += 1;
+        self.pc = match self.program[pc].execute(&mut self.registers) {
+            Control::Next => pc.checked_add(1),
+            Control::Jump(offset) => pc.checked_add_signed(offset),
+            Control::Halt => None,
+        };
+        true
+    }
+
+    /// Runs until the machine halts, returning the final registers.
+    pub fn run(&mut self) -> Result<&Registers, VmError> {
+        self.run_traced(|_| {})
+    }
+
+    /// Like [`Machine::run`], but calls `tracer` before each instruction.
+    pub fn run_traced(&mut self, mut tracer: impl FnMut(&Trace<I>)) -> Result<&Registers, VmError> {
+        while let Some(pc) = self.pc() {
+            if let Some(limit) = self.step_limit.filter(|&limit| self.steps >= limit) {
+                return Err(VmError::StepLimit { limit, pc });
+            }
+            tracer(&Trace {
+                step: self.steps,
+                pc,
+                instruction: &self.program[pc],
+                registers: &self.registers,
+            });
+            self.step();
+        }
+        Ok(&self.registers)
+    }
+}
+
+/// The instruction set of 2015's day 23.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instr {
+    /// `hlf r`: halves register `r`.
+    Hlf(Register),
+    /// `tpl r`: triples register `r`.
+    Tpl(Register),
+    /// `inc r`: increments register `r`.
+    Inc(Register),
+    /// `jmp offset`: jumps by `offset`.
+    Jmp(isize),
+    /// `jie r, offset`: jumps by `offset` if register `r` is even.
+    Jie(Register, isize),
+    /// `jio r, offset`: jumps by `offset` if register `r` is one.
+    Jio(Register, isize),
+}
+
+impl TryFrom<Line<'_>> for Instr {
+    type Error = ParseError;
+
+    fn try_from(line: Line) -> Result<Self, Self::Error> {
+        let op = line.text.split(' ').next().unwrap_or_default();
+        Ok(match op {
+            "hlf" => Instr::Hlf(crate::parse!(line, "hlf {register}").map(|(r,)| r)?),
+            "tpl" => Instr::Tpl(crate::parse!(line, "tpl {register}").map(|(r,)| r)?),
+            "inc" => Instr::Inc(crate::parse!(line, "inc {register}").map(|(r,)| r)?),
+            "jmp" => Instr::Jmp(crate::parse!(line, "jmp {offset}").map(|(o,)| o)?),
+            "jie" => {
+                let (register, offset) = crate::parse!(line, "jie {register}, {offset}")?;
+                Instr::Jie(register, offset)
+            }
+            "jio" => {
+                let (register, offset) = crate::parse!(line, "jio {register}, {offset}")?;
+                Instr::Jio(register, offset)
+            }
+            _ => return Err(line.error_at(op, "unknown instruction")),
+        })
+    }
+}
+
+impl Display for Instr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instr::Hlf(r) => write!(f, "hlf {r}"),
+            Instr::Tpl(r) => write!(f, "tpl {r}"),
+            Instr::Inc(r) => write!(f, "inc {r}"),
+            Instr::Jmp(offset) => write!(f, "jmp {offset:+}"),
+            Instr::Jie(r, offset) => write!(f, "jie {r}, {offset:+}"),
+            Instr::Jio(r, offset) => write!(f, "jio {r}, {offset:+}"),
+        }
+    }
+}
+
+impl Instruction for Instr {
+    fn execute(&self, registers: &mut Registers) -> Control {
+        match *self {
+            Instr::Hlf(r) => registers[r] /= 2,
+            Instr::Tpl(r) => registers[r] *= 3,
+            Instr::Inc(r) => registers[r] += 1,
+            Instr::Jmp(offset) => return Control::Jump(offset),
+            Instr::Jie(r, offset) if registers[r] % 2 == 0 => return Control::Jump(offset),
+            Instr::Jio(r, offset) if registers[r] == 1 => return Control::Jump(offset),
+            Instr::Jie(..) | Instr::Jio(..) => {}
+        }
+        Control::Next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parse;
+
+    fn program(input: &str) -> Vec<Instr> {
+        parse::lines(input)
+            .map(Instr::try_from)
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn parses_instructions() {
+        let a = Register::new('a').unwrap();
+        let b = Register::new('b').unwrap();
+        assert_eq!(
+            program("hlf a\ntpl b\ninc a\njmp +23\njie a, +4\njio b, -7"),
+            vec![
+                Instr::Hlf(a),
+                Instr::Tpl(b),
+                Instr::Inc(a),
+                Instr::Jmp(23),
+                Instr::Jie(a, 4),
+                Instr::Jio(b, -7),
+            ]
+        );
+    }
+
+    #[test]
+    fn displays_instructions() {
+        let input = "hlf a\njmp +23\njie a, +4\njio b, -7";
+        let shown: Vec<_> = program(input).iter().map(Instr::to_string).collect();
+        assert_eq!(shown.join("\n"), input);
+    }
+
+    #[test]
+    fn reports_parse_errors() {
+        let err = parse::lines("inc a\nmul a, 2")
+            .map(Instr::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap_err();
+        assert_eq!((err.line, err.column, err.width), (2, 1, 3));
+        assert_eq!(err.message, "unknown instruction");
+
+        let line = parse::lines("jio A, +2").next().unwrap();
+        let err = Instr::try_from(line).unwrap_err();
+        assert_eq!(
+            (err.column, err.message.as_str()),
+            (5, "register: invalid register \"A\"")
+        );
+    }
+
+    #[test]
+    fn runs_programs() {
+        let program = program("inc a\njio a, +2\ntpl a\ninc a");
+        let mut machine = Machine::new(&program);
+        assert_eq!(machine.run().unwrap()['a'], 2);
+        assert_eq!(machine.steps(), 3);
+        assert_eq!(machine.pc(), None);
+        assert!(!machine.step());
+
+        let mut machine = Machine::new(&program).with_register('a', 1);
+        assert_eq!(machine.run().unwrap()['a'], 7);
+    }
+
+    #[test]
+    fn halts_on_jumps_out_of_the_program() {
+        let program = program("jmp -1\ninc a");
+        let mut machine = Machine::new(&program);
+        assert!(machine.step());
+        assert_eq!(machine.pc(), None);
+        assert_eq!(machine.registers()['a'], 0);
+    }
+
+    #[test]
+    fn enforces_step_limits() {
+        let program = program("inc a\njmp -1");
+        let mut machine = Machine::new(&program).with_step_limit(5);
+        assert_eq!(machine.run(), Err(VmError::StepLimit { limit: 5, pc: 1 }));
+        assert_eq!(machine.registers()['a'], 3);
+    }
+
+    #[test]
+    fn traces_execution() {
+        let program = program("inc a\njio a, +2\ntpl a\ninc a");
+        let mut trace = vec![];
+        Machine::new(&program)
+            .run_traced(|t| trace.push(t.to_string()))
+            .unwrap();
+        assert_eq!(
+            trace,
+            vec![
+                "   0 @ 0: inc a        -",
+                "   1 @ 1: jio a, +2    a=1",
+                "   2 @ 3: inc a        a=1",
+            ]
+        );
+    }
+}
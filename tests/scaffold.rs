@@ -0,0 +1,65 @@
+//! Runs `cargo scaffold --download` in a scratch project, with a stand-in for aoc-cli that writes
+//! a puzzle description and an input.
+#![cfg(all(unix, feature = "test_lib"))]
+
+use std::env;
+use std::fs;
+use std::os::unix::fs::PermissionsExt;
+use std::path::Path;
+use std::process::Command;
+
+/// Answers `aoc -V` and writes the files passed with `--input-file` and `--puzzle-file`.
+const FAKE_AOC: &str = r#"#!/bin/sh
+while [ $# -gt 0 ]; do
+    case "$1" in
+        --input-file) echo 42 > "$2"; shift ;;
+        --puzzle-file) printf '%s\n' '\--- Day 5: Doesn'"'"'t He Have Intern-Elves For This? ---' > "$2"; shift ;;
+    esac
+    shift
+done
+"#;
+
+fn create_project(root: &Path) {
+    for dir in [
+        "src/days",
+        "src/bin",
+        "data/inputs",
+        "data/examples",
+        "data/puzzles",
+        "bin",
+    ] {
+        fs::create_dir_all(root.join(dir)).unwrap();
+    }
+    fs::write(root.join("src/days/mod.rs"), "").unwrap();
+
+    let aoc = root.join("bin/aoc");
+    fs::write(&aoc, FAKE_AOC).unwrap();
+    fs::set_permissions(&aoc, fs::Permissions::from_mode(0o755)).unwrap();
+}
+
+#[test]
+fn titles_downloaded_puzzles() {
+    let root = env::temp_dir().join(format!("aoc-scaffold-download-{}", std::process::id()));
+    create_project(&root);
+
+    let path = format!(
+        "{}:{}",
+        root.join("bin").display(),
+        env::var("PATH").unwrap_or_default()
+    );
+    let output = Command::new(env!("CARGO_BIN_EXE_advent_of_code"))
+        .args(["scaffold", "5", "--download"])
+        .current_dir(&root)
+        .env("PATH", path)
+        .output()
+        .unwrap();
+    let module = fs::read_to_string(root.join("src/days/day05.rs"));
+    let input = fs::read_to_string(root.join("data/inputs/05.txt"));
+    fs::remove_dir_all(&root).unwrap();
+
+    assert!(output.status.success(), "{output:?}");
+    assert!(module
+        .unwrap()
+        .starts_with("//! Day 5: Doesn't He Have Intern-Elves For This?\n"));
+    assert_eq!(input.unwrap(), "42\n");
+}